@@ -9,7 +9,7 @@ use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
 use sc_transaction_pool_api::TransactionPool;
-use solochain_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use solochain_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Nonce};
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
@@ -20,6 +20,8 @@ pub struct FullDeps<C, P> {
 	pub client: Arc<C>,
 	/// Transaction pool instance.
 	pub pool: Arc<P>,
+	/// Opens a stream of the hashes of blocks imported as the new best block.
+	pub best_block_imports: Arc<dyn Fn() -> pallet_signal_rpc::BestBlockImports<Hash> + Send + Sync>,
 }
 
 /// Instantiate all full RPC extensions.
@@ -32,17 +34,26 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_certificate_rpc::CertificateRuntimeApi<Block, AccountId, BlockNumber, Hash>,
+	C::Api: pallet_infostratus_rpc::InfostratusRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+	C::Api: pallet_signal_rpc::SignalRuntimeApi<Block, AccountId, Hash, BlockNumber>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
+	use pallet_certificate_rpc::{Certificate, CertificateApiServer};
+	use pallet_infostratus_rpc::{Infostratus, InfostratusApiServer};
+	use pallet_signal_rpc::{Signal, SignalApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = RpcModule::new(());
-	let FullDeps { client, pool } = deps;
+	let FullDeps { client, pool, best_block_imports } = deps;
 
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Certificate::new(client.clone()).into_rpc())?;
+	module.merge(Infostratus::new(client.clone()).into_rpc())?;
+	module.merge(Signal::new(client, move || best_block_imports()).into_rpc())?;
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
@@ -1,7 +1,7 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
-use futures::FutureExt;
-use sc_client_api::{Backend, BlockBackend};
+use futures::{future, FutureExt, StreamExt};
+use sc_client_api::{Backend, BlockBackend, BlockchainEvents};
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_consensus_grandpa::SharedVoterState;
 use sc_service::{error::Error as ServiceError, Configuration, TaskManager, WarpSyncConfig};
@@ -214,9 +214,23 @@ pub fn new_full<
 	let rpc_extensions_builder = {
 		let client = client.clone();
 		let pool = transaction_pool.clone();
+		// Feeds `signal_subscribe` with every block imported as the new best block.
+		let best_block_imports = {
+			let client = client.clone();
+			Arc::new(move || {
+				client
+					.import_notification_stream()
+					.filter_map(|notification| future::ready(notification.is_new_best.then_some(notification.hash)))
+					.boxed()
+			})
+		};
 
 		Box::new(move |_| {
-			let deps = crate::rpc::FullDeps { client: client.clone(), pool: pool.clone() };
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				best_block_imports: best_block_imports.clone(),
+			};
 			crate::rpc::create_full(deps).map_err(Into::into)
 		})
 	};
@@ -0,0 +1,335 @@
+//! Rendering of certificates as W3C Verifiable Credentials and parsing of such documents back
+//! into the on-chain facts they claim.
+//!
+//! Accounts are identified as `did:fennel:` followed by the hex encoding of the account id.
+//! Instead of a cryptographic signature, the `proof` section points at the block and extrinsic
+//! that issued the certificate, so a verifier checks the document against chain state.
+
+use codec::{Decode, DecodeAll, Encode};
+use pallet_certificate_runtime_api::CertificateDetails;
+use serde_json::{json, Value};
+use sp_core::bytes::{from_hex, to_hex};
+
+/// The base context of a W3C Verifiable Credentials 2.0 document.
+pub const CREDENTIALS_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+/// The vocabulary used for Fennel specific credential terms.
+pub const FENNEL_VOCABULARY: &str = "https://fennellabs.com/credentials#";
+/// The prefix of the decentralized identifiers used for Fennel accounts.
+pub const DID_PREFIX: &str = "did:fennel:";
+/// The credential type assigned to certificates.
+pub const CREDENTIAL_TYPE: &str = "FennelCertificate";
+/// The proof type referencing the block and extrinsic that issued a certificate.
+pub const PROOF_TYPE: &str = "FennelBlockInclusionProof";
+
+/// Reasons a document cannot be read as a Fennel certificate credential.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CredentialError {
+	/// A required field is missing or has the wrong JSON type.
+	MissingField(&'static str),
+	/// A field does not hold a valid `did:fennel:` identifier or hex value.
+	InvalidField(&'static str),
+	/// The document is not a Fennel certificate credential.
+	UnsupportedType,
+}
+
+impl core::fmt::Display for CredentialError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::MissingField(field) => write!(f, "missing field `{field}`"),
+			Self::InvalidField(field) => write!(f, "invalid field `{field}`"),
+			Self::UnsupportedType => write!(f, "not a {CREDENTIAL_TYPE} credential"),
+		}
+	}
+}
+
+/// The on-chain facts claimed by a certificate credential.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CredentialClaims<AccountId, Hash> {
+	/// The account that issued the certificate.
+	pub issuer: AccountId,
+	/// The account that holds the certificate.
+	pub subject: AccountId,
+	/// The claim type as rendered: UTF-8 text, or hex if it is not valid UTF-8.
+	pub claim_type: String,
+	/// Hash of the off-chain certificate content.
+	pub content_hash: Hash,
+	/// The block at which the certificate lapses, if any.
+	pub expires_at: Option<u64>,
+	/// The authorities above the issuer, if it was a certificate authority.
+	pub authority_chain: Option<Vec<AccountId>>,
+	/// The block in which the certificate was issued.
+	pub block_number: u64,
+	/// The hash of the block in which the certificate was issued.
+	pub block_hash: Hash,
+	/// The index of the issuing extrinsic within its block.
+	pub extrinsic_index: u32,
+}
+
+impl<AccountId: PartialEq, Hash: PartialEq> CredentialClaims<AccountId, Hash> {
+	/// Returns whether every claim agrees with the certificate found on chain, given the hash
+	/// of the canonical block at `block_number`.
+	pub fn matches<BlockNumber>(
+		&self,
+		details: &CertificateDetails<AccountId, BlockNumber, Hash>,
+		canonical_block_hash: &Hash,
+	) -> bool
+	where
+		BlockNumber: Copy + Into<u64>,
+	{
+		claim_type(&details.claim_type) == self.claim_type &&
+			details.content_hash == self.content_hash &&
+			details.expires_at.map(Into::into) == self.expires_at &&
+			details.authority_chain == self.authority_chain &&
+			details.issued_at.into() == self.block_number &&
+			details.extrinsic_index == Some(self.extrinsic_index) &&
+			*canonical_block_hash == self.block_hash
+	}
+}
+
+fn did<AccountId: Encode>(account: &AccountId) -> String {
+	format!("{DID_PREFIX}{}", to_hex(&account.encode(), false))
+}
+
+fn claim_type(claim_type: &[u8]) -> String {
+	String::from_utf8(claim_type.to_vec()).unwrap_or_else(|_| to_hex(claim_type, false))
+}
+
+/// Renders the certificate from `issuer` to `subject` as a Verifiable Credential whose proof
+/// references `block_hash`, the hash of the block the certificate was issued in. Returns `None`
+/// if the certificate does not record its issuing extrinsic, as then there is nothing to prove
+/// its inclusion with.
+pub fn render_credential<AccountId, BlockNumber, Hash>(
+	issuer: &AccountId,
+	subject: &AccountId,
+	details: &CertificateDetails<AccountId, BlockNumber, Hash>,
+	block_hash: &Hash,
+) -> Option<Value>
+where
+	AccountId: Encode,
+	BlockNumber: Copy + Into<u64>,
+	Hash: Encode,
+{
+	let extrinsic_index = details.extrinsic_index?;
+	let mut credential_subject = json!({
+		"id": did(subject),
+		"claimType": claim_type(&details.claim_type),
+		"contentHash": to_hex(&details.content_hash.encode(), false),
+	});
+	if let Some(expires_at) = details.expires_at {
+		credential_subject["expiresAtBlock"] = json!(expires_at.into());
+	}
+	if let Some(chain) = &details.authority_chain {
+		credential_subject["authorityChain"] = json!(chain.iter().map(did).collect::<Vec<_>>());
+	}
+	Some(json!({
+		"@context": [CREDENTIALS_CONTEXT, { "@vocab": FENNEL_VOCABULARY }],
+		"type": ["VerifiableCredential", CREDENTIAL_TYPE],
+		"issuer": did(issuer),
+		"credentialSubject": credential_subject,
+		"proof": {
+			"type": PROOF_TYPE,
+			"blockNumber": details.issued_at.into(),
+			"blockHash": to_hex(&block_hash.encode(), false),
+			"extrinsicIndex": extrinsic_index,
+		},
+	}))
+}
+
+fn field<'a>(value: &'a Value, path: &[&str], name: &'static str) -> Result<&'a Value, CredentialError> {
+	path.iter()
+		.try_fold(value, |value, key| value.get(key))
+		.ok_or(CredentialError::MissingField(name))
+}
+
+fn decode_hex<T: Decode>(value: &Value, prefix: &str, name: &'static str) -> Result<T, CredentialError> {
+	let text = value.as_str().ok_or(CredentialError::MissingField(name))?;
+	let hex = text.strip_prefix(prefix).ok_or(CredentialError::InvalidField(name))?;
+	let bytes = from_hex(hex).map_err(|_| CredentialError::InvalidField(name))?;
+	T::decode_all(&mut &bytes[..]).map_err(|_| CredentialError::InvalidField(name))
+}
+
+fn optional_field<'a>(value: &'a Value, path: &[&str]) -> Option<&'a Value> {
+	path.iter().try_fold(value, |value, key| value.get(key))
+}
+
+/// Reads the claims of a credential previously produced by [`render_credential`].
+pub fn parse_credential<AccountId, Hash>(
+	credential: &Value,
+) -> Result<CredentialClaims<AccountId, Hash>, CredentialError>
+where
+	AccountId: Decode,
+	Hash: Decode,
+{
+	let types = field(credential, &["type"], "type")?
+		.as_array()
+		.ok_or(CredentialError::MissingField("type"))?;
+	if !types.iter().any(|t| t.as_str() == Some(CREDENTIAL_TYPE)) {
+		return Err(CredentialError::UnsupportedType);
+	}
+	if field(credential, &["proof", "type"], "proof.type")?.as_str() != Some(PROOF_TYPE) {
+		return Err(CredentialError::UnsupportedType);
+	}
+	Ok(CredentialClaims {
+		issuer: decode_hex(field(credential, &["issuer"], "issuer")?, DID_PREFIX, "issuer")?,
+		subject: decode_hex(
+			field(credential, &["credentialSubject", "id"], "credentialSubject.id")?,
+			DID_PREFIX,
+			"credentialSubject.id",
+		)?,
+		claim_type: field(credential, &["credentialSubject", "claimType"], "credentialSubject.claimType")?
+			.as_str()
+			.ok_or(CredentialError::InvalidField("credentialSubject.claimType"))?
+			.to_owned(),
+		content_hash: decode_hex(
+			field(credential, &["credentialSubject", "contentHash"], "credentialSubject.contentHash")?,
+			"",
+			"credentialSubject.contentHash",
+		)?,
+		expires_at: optional_field(credential, &["credentialSubject", "expiresAtBlock"])
+			.map(|expires_at| {
+				expires_at.as_u64().ok_or(CredentialError::InvalidField("credentialSubject.expiresAtBlock"))
+			})
+			.transpose()?,
+		authority_chain: optional_field(credential, &["credentialSubject", "authorityChain"])
+			.map(|chain| {
+				chain
+					.as_array()
+					.ok_or(CredentialError::InvalidField("credentialSubject.authorityChain"))?
+					.iter()
+					.map(|authority| decode_hex(authority, DID_PREFIX, "credentialSubject.authorityChain"))
+					.collect::<Result<Vec<_>, _>>()
+			})
+			.transpose()?,
+		block_number: field(credential, &["proof", "blockNumber"], "proof.blockNumber")?
+			.as_u64()
+			.ok_or(CredentialError::InvalidField("proof.blockNumber"))?,
+		block_hash: decode_hex(
+			field(credential, &["proof", "blockHash"], "proof.blockHash")?,
+			"",
+			"proof.blockHash",
+		)?,
+		extrinsic_index: field(credential, &["proof", "extrinsicIndex"], "proof.extrinsicIndex")?
+			.as_u64()
+			.and_then(|index| u32::try_from(index).ok())
+			.ok_or(CredentialError::InvalidField("proof.extrinsicIndex"))?,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::H256;
+
+	fn details() -> CertificateDetails<u64, u32, H256> {
+		CertificateDetails {
+			claim_type: b"course-completion".to_vec(),
+			content_hash: H256::repeat_byte(7),
+			issued_at: 42,
+			extrinsic_index: Some(3),
+			expires_at: Some(100),
+			authority_chain: Some(vec![9]),
+		}
+	}
+
+	#[test]
+	fn renders_certificate_as_credential() {
+		let credential = render_credential(&1u64, &2u64, &details(), &H256::repeat_byte(1)).unwrap();
+		assert_eq!(credential["type"][1], CREDENTIAL_TYPE);
+		assert_eq!(credential["issuer"], "did:fennel:0x0100000000000000");
+		assert_eq!(credential["credentialSubject"]["id"], "did:fennel:0x0200000000000000");
+		assert_eq!(credential["credentialSubject"]["claimType"], "course-completion");
+		assert_eq!(credential["credentialSubject"]["expiresAtBlock"], 100);
+		assert_eq!(credential["credentialSubject"]["authorityChain"][0], "did:fennel:0x0900000000000000");
+		assert_eq!(credential["proof"]["blockNumber"], 42);
+		assert_eq!(credential["proof"]["extrinsicIndex"], 3);
+	}
+
+	#[test]
+	fn parses_rendered_credential() {
+		let credential = render_credential(&1u64, &2u64, &details(), &H256::repeat_byte(1)).unwrap();
+		let claims = parse_credential::<u64, H256>(&credential).unwrap();
+		assert_eq!(
+			claims,
+			CredentialClaims {
+				issuer: 1,
+				subject: 2,
+				claim_type: "course-completion".into(),
+				content_hash: H256::repeat_byte(7),
+				expires_at: Some(100),
+				authority_chain: Some(vec![9]),
+				block_number: 42,
+				block_hash: H256::repeat_byte(1),
+				extrinsic_index: 3,
+			}
+		);
+		assert!(claims.matches(&details(), &H256::repeat_byte(1)));
+		assert!(!claims.matches(&details(), &H256::repeat_byte(2)));
+		let reissued = CertificateDetails { extrinsic_index: Some(4), ..details() };
+		assert!(!claims.matches(&reissued, &H256::repeat_byte(1)));
+	}
+
+	#[test]
+	fn tampered_claims_do_not_match() {
+		let credential = render_credential(&1u64, &2u64, &details(), &H256::repeat_byte(1)).unwrap();
+		let tampered: [(&[&str], Value); 8] = [
+			(&["credentialSubject", "claimType"], json!("membership")),
+			(&["credentialSubject", "contentHash"], json!(to_hex(&H256::repeat_byte(8).encode(), false))),
+			(&["credentialSubject", "expiresAtBlock"], json!(200)),
+			(&["credentialSubject", "authorityChain"], json!([did(&8u64)])),
+			(&["credentialSubject", "authorityChain"], json!([did(&9u64), did(&8u64)])),
+			(&["proof", "blockNumber"], json!(43)),
+			(&["proof", "blockHash"], json!(to_hex(&H256::repeat_byte(2).encode(), false))),
+			(&["proof", "extrinsicIndex"], json!(4)),
+		];
+		for (path, value) in tampered {
+			let mut credential = credential.clone();
+			let (last, parents) = path.split_last().unwrap();
+			parents.iter().fold(&mut credential, |value, key| &mut value[*key])[*last] = value;
+			let claims = parse_credential::<u64, H256>(&credential).unwrap();
+			assert!(!claims.matches(&details(), &H256::repeat_byte(1)), "tampered {path:?} matched");
+		}
+
+		// Dropping optional claims the certificate has does not match either
+		for key in ["expiresAtBlock", "authorityChain"] {
+			let mut credential = credential.clone();
+			credential["credentialSubject"].as_object_mut().unwrap().remove(key);
+			let claims = parse_credential::<u64, H256>(&credential).unwrap();
+			assert!(!claims.matches(&details(), &H256::repeat_byte(1)), "missing {key} matched");
+		}
+	}
+
+	#[test]
+	fn migrated_certificates_are_neither_exported_nor_matched() {
+		let credential = render_credential(&1u64, &2u64, &details(), &H256::repeat_byte(1)).unwrap();
+		let claims = parse_credential::<u64, H256>(&credential).unwrap();
+		let migrated = CertificateDetails { extrinsic_index: None, ..details() };
+		assert_eq!(render_credential(&1u64, &2u64, &migrated, &H256::repeat_byte(1)), None);
+		assert!(!claims.matches(&migrated, &H256::repeat_byte(1)));
+	}
+
+	#[test]
+	fn rejects_trailing_bytes_in_hex_fields() {
+		let mut credential = render_credential(&1u64, &2u64, &details(), &H256::repeat_byte(1)).unwrap();
+		credential["issuer"] = json!("did:fennel:0x010000000000000000");
+		assert_eq!(
+			parse_credential::<u64, H256>(&credential),
+			Err(CredentialError::InvalidField("issuer"))
+		);
+	}
+
+	#[test]
+	fn rejects_foreign_or_tampered_credentials() {
+		let mut credential = render_credential(&1u64, &2u64, &details(), &H256::repeat_byte(1)).unwrap();
+		credential["issuer"] = json!("did:example:123");
+		assert_eq!(
+			parse_credential::<u64, H256>(&credential),
+			Err(CredentialError::InvalidField("issuer"))
+		);
+		credential["type"] = json!(["VerifiableCredential"]);
+		assert_eq!(parse_credential::<u64, H256>(&credential), Err(CredentialError::UnsupportedType));
+		assert_eq!(
+			parse_credential::<u64, H256>(&json!({ "type": [CREDENTIAL_TYPE] })),
+			Err(CredentialError::MissingField("proof.type"))
+		);
+	}
+}
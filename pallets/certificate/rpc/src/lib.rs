@@ -0,0 +1,207 @@
+//! RPC interface for the certificate pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, NumberFor, UniqueSaturatedFrom};
+
+pub mod credential;
+
+pub use pallet_certificate_runtime_api::CertificateApi as CertificateRuntimeApi;
+
+#[rpc(client, server)]
+pub trait CertificateApi<BlockHash, AccountId> {
+	/// Returns whether `sender` holds an active, unexpired certificate for `recipient`.
+	#[method(name = "certificate_isCertified")]
+	fn is_certified(&self, sender: AccountId, recipient: AccountId, at: Option<BlockHash>) -> RpcResult<bool>;
+
+	/// Returns whether the certificate from `sender` to `recipient` is active and its chain of
+	/// issuing authorities has not been revoked.
+	#[method(name = "certificate_verify")]
+	fn verify_certificate(
+		&self,
+		sender: AccountId,
+		recipient: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<bool>;
+
+	/// Returns the recipients of all active certificates issued by `sender`.
+	#[method(name = "certificate_issuedBy")]
+	fn certificates_issued_by(&self, sender: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+
+	/// Returns the senders of all active certificates held by `recipient`.
+	#[method(name = "certificate_heldBy")]
+	fn certificates_held_by(&self, recipient: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+
+	/// Exports the certificate from `sender` to `recipient` as a W3C Verifiable Credential, or
+	/// `None` when there is no active certificate. Fails for certificates migrated from storage
+	/// that did not record their issuing extrinsic.
+	#[method(name = "certificate_exportCredential")]
+	fn export_credential(
+		&self,
+		sender: AccountId,
+		recipient: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<serde_json::Value>>;
+
+	/// Returns whether an exported credential still describes a valid certificate: the
+	/// certificate must verify and match the content hash, issuing block and extrinsic claimed
+	/// by the credential.
+	#[method(name = "certificate_verifyCredential")]
+	fn verify_credential(&self, credential: serde_json::Value, at: Option<BlockHash>) -> RpcResult<bool>;
+}
+
+/// Provides RPC methods to query certificates.
+pub struct Certificate<C, B> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Certificate<C, B> {
+	/// Creates a new instance of the Certificate Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The provided document is not a certificate credential.
+	InvalidCredential,
+	/// The certificate does not record the extrinsic that issued it.
+	UnknownProvenance,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidCredential => 2,
+			Error::UnknownProvenance => 3,
+		}
+	}
+}
+
+fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+}
+
+impl<C, Block, AccountId> CertificateApiServer<<Block as BlockT>::Hash, AccountId> for Certificate<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CertificateRuntimeApi<Block, AccountId, NumberFor<Block>, Block::Hash>,
+	AccountId: Codec + Clone + PartialEq + Send + Sync + 'static,
+	NumberFor<Block>: Into<u64>,
+{
+	fn is_certified(
+		&self,
+		sender: AccountId,
+		recipient: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<bool> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.is_certified(at_hash, sender, recipient)
+			.map_err(|e| map_err(e, "Unable to query certificate."))
+	}
+
+	fn verify_certificate(
+		&self,
+		sender: AccountId,
+		recipient: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<bool> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.verify_certificate(at_hash, sender, recipient)
+			.map_err(|e| map_err(e, "Unable to verify certificate."))
+	}
+
+	fn certificates_issued_by(&self, sender: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<AccountId>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.certificates_issued_by(at_hash, sender)
+			.map_err(|e| map_err(e, "Unable to query issued certificates."))
+	}
+
+	fn certificates_held_by(&self, recipient: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<AccountId>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.certificates_held_by(at_hash, recipient)
+			.map_err(|e| map_err(e, "Unable to query held certificates."))
+	}
+
+	fn export_credential(
+		&self,
+		sender: AccountId,
+		recipient: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<serde_json::Value>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let Some(details) = self
+			.client
+			.runtime_api()
+			.certificate(at_hash, sender.clone(), recipient.clone())
+			.map_err(|e| map_err(e, "Unable to query certificate."))?
+		else {
+			return Ok(None);
+		};
+		if details.extrinsic_index.is_none() {
+			return Err(ErrorObject::owned(
+				Error::UnknownProvenance.into(),
+				"Certificate predates provenance records and cannot be exported.",
+				None::<()>,
+			));
+		}
+		let block_hash = self
+			.client
+			.hash(details.issued_at)
+			.map_err(|e| map_err(e, "Unable to query issuing block."))?
+			.ok_or_else(|| map_err("block not found", "Unable to query issuing block."))?;
+		Ok(credential::render_credential(&sender, &recipient, &details, &block_hash))
+	}
+
+	fn verify_credential(&self, credential: serde_json::Value, at: Option<Block::Hash>) -> RpcResult<bool> {
+		let claims = credential::parse_credential::<AccountId, Block::Hash>(&credential).map_err(|e| {
+			ErrorObject::owned(Error::InvalidCredential.into(), "Invalid credential.", Some(e.to_string()))
+		})?;
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		let verified = api
+			.verify_certificate(at_hash, claims.issuer.clone(), claims.subject.clone())
+			.map_err(|e| map_err(e, "Unable to verify certificate."))?;
+		if !verified {
+			return Ok(false);
+		}
+		let Some(details) = api
+			.certificate(at_hash, claims.issuer.clone(), claims.subject.clone())
+			.map_err(|e| map_err(e, "Unable to query certificate."))?
+		else {
+			return Ok(false);
+		};
+		let block_number = NumberFor::<Block>::unique_saturated_from(claims.block_number);
+		let Some(block_hash) = self
+			.client
+			.hash(block_number)
+			.map_err(|e| map_err(e, "Unable to query issuing block."))?
+		else {
+			return Ok(false);
+		};
+		Ok(claims.matches(&details, &block_hash))
+	}
+}
@@ -0,0 +1,56 @@
+//! Runtime API definition for the certificate pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+
+/// The publicly verifiable details of an active certificate.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct CertificateDetails<AccountId, BlockNumber, Hash> {
+    /// Application-defined kind of claim.
+    pub claim_type: Vec<u8>,
+    /// Hash of the off-chain certificate content.
+    pub content_hash: Hash,
+    /// The block in which the certificate was issued.
+    pub issued_at: BlockNumber,
+    /// The index within `issued_at` of the extrinsic that issued the certificate, or `None` if
+    /// the certificate was migrated from storage that did not record it.
+    pub extrinsic_index: Option<u32>,
+    /// The block at which the certificate lapses, if any.
+    pub expires_at: Option<BlockNumber>,
+    /// The authorities above the sender at the time of issuance, if the sender was a
+    /// certificate authority.
+    pub authority_chain: Option<Vec<AccountId>>,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait CertificateApi<AccountId, BlockNumber, Hash>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+    {
+        /// Returns whether `sender` holds an active certificate for `recipient` whose chain of
+        /// issuing authorities has not been revoked.
+        fn verify_certificate(sender: AccountId, recipient: AccountId) -> bool;
+
+        /// Returns whether `sender` holds an active, unexpired certificate for `recipient`.
+        fn is_certified(sender: AccountId, recipient: AccountId) -> bool;
+
+        /// Returns the recipients of all active certificates issued by `sender`.
+        fn certificates_issued_by(sender: AccountId) -> Vec<AccountId>;
+
+        /// Returns the senders of all active certificates held by `recipient`.
+        fn certificates_held_by(recipient: AccountId) -> Vec<AccountId>;
+
+        /// Returns the details of the active certificate from `sender` to `recipient`.
+        fn certificate(
+            sender: AccountId,
+            recipient: AccountId,
+        ) -> Option<CertificateDetails<AccountId, BlockNumber, Hash>>;
+    }
+}
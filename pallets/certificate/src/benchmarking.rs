@@ -3,9 +3,13 @@
 
 use super::*;
 use crate::Pallet as Certificate;
+use alloc::{vec, vec::Vec};
 use frame_benchmarking::{account as benchmark_account, v2::*};
-use frame_support::{traits::Currency, sp_runtime::traits::Bounded};
-use frame_system::RawOrigin;
+use frame_support::{
+	traits::{fungible::Mutate, EnsureOrigin},
+	sp_runtime::traits::Bounded,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 
 pub fn get_account<T: Config>(name: &'static str) -> T::AccountId {
 	benchmark_account(name, 0, 0)
@@ -15,6 +19,10 @@ pub fn get_origin<T: Config>(name: &'static str) -> RawOrigin<T::AccountId> {
 	RawOrigin::Signed(get_account::<T>(name))
 }
 
+pub fn get_claim_type<T: Config>() -> BoundedVec<u8, T::MaxClaimTypeLength> {
+	BoundedVec::try_from(vec![b'X'; T::MaxClaimTypeLength::get() as usize]).unwrap()
+}
+
 pub fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -22,46 +30,189 @@ pub fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent)
 #[benchmarks]
 mod benchmarks {
 	use super::*;
-	type BalanceOf<T> =
-		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	#[benchmark]
-	fn send_certificate() -> Result<(), BenchmarkError> {
-		let target = get_account::<T>("James");
+	fn expire_certificate() -> Result<(), BenchmarkError> {
+		let target = get_account::<T>("Leonard");
 		let caller = get_origin::<T>("Spock");
 		let caller_account: T::AccountId = get_account::<T>("Spock");
 
-		T::Currency::make_free_balance_be(&caller_account, BalanceOf::<T>::max_value());
+		T::Currency::set_balance(&caller_account, BalanceOf::<T>::max_value() / 2u32.into());
+		let expiry = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		Certificate::<T>::send_certificate(
+			caller.clone().into(),
+			target.clone(),
+			get_claim_type::<T>(),
+			T::Hash::default(),
+			Some(expiry),
+		)?;
+		Certificate::<T>::accept_certificate(RawOrigin::Signed(target.clone()).into(), caller_account.clone())?;
 
-		#[extrinsic_call]
-		_(caller, target);
+		#[block]
+		{
+			ExpiryQueue::<T>::remove(expiry, (&caller_account, &target));
+			Certificate::<T>::expire_certificate(&caller_account, &target);
+		}
 
-		let caller_account_id: T::AccountId = get_account::<T>("Spock");
-		let target_account_id: T::AccountId = get_account::<T>("James");
-		assert!(CertificateList::<T>::contains_key(caller_account_id.clone(), target_account_id.clone()));
-		assert_last_event::<T>(Event::CertificateSent { sender: caller_account_id, recipient: target_account_id }.into());
+		assert!(!CertificateList::<T>::contains_key(caller_account.clone(), target.clone()));
+		assert_last_event::<T>(Event::CertificateExpired { sender: caller_account, recipient: target }.into());
+		Ok(())
+	}
 
+	#[benchmark]
+	fn accept_certificate() -> Result<(), BenchmarkError> {
+		let target = get_account::<T>("Nyota");
+		let caller = get_origin::<T>("Spock");
+		let caller_account: T::AccountId = get_account::<T>("Spock");
+
+		T::Currency::set_balance(&caller_account, BalanceOf::<T>::max_value() / 2u32.into());
+		Certificate::<T>::send_certificate(
+			caller.into(),
+			target.clone(),
+			get_claim_type::<T>(),
+			T::Hash::default(),
+			Some(BlockNumberFor::<T>::max_value()),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(target.clone()), caller_account.clone());
+
+		assert!(CertificateList::<T>::contains_key(caller_account.clone(), target.clone()));
+		assert_last_event::<T>(Event::CertificateAccepted { sender: caller_account, recipient: target }.into());
 		Ok(())
 	}
 
 	#[benchmark]
-	fn revoke_certificate() -> Result<(), BenchmarkError> {
-		let target = get_account::<T>("Montgomery");
+	fn reject_certificate() -> Result<(), BenchmarkError> {
+		let target = get_account::<T>("Hikaru");
 		let caller = get_origin::<T>("Spock");
 		let caller_account: T::AccountId = get_account::<T>("Spock");
 
-		T::Currency::make_free_balance_be(&caller_account, BalanceOf::<T>::max_value());
-		// Use direct call for setup, not the macro shorthand
-		Certificate::<T>::send_certificate(caller.clone().into(), target.clone())?;
+		T::Currency::set_balance(&caller_account, BalanceOf::<T>::max_value() / 2u32.into());
+		Certificate::<T>::send_certificate(
+			caller.into(),
+			target.clone(),
+			get_claim_type::<T>(),
+			T::Hash::default(),
+			Some(BlockNumberFor::<T>::max_value()),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(target.clone()), caller_account.clone());
+
+		assert!(!PendingCertificates::<T>::contains_key(caller_account.clone(), target.clone()));
+		assert_last_event::<T>(Event::CertificateRejected { sender: caller_account, recipient: target }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_authority() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AuthorityOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let authority = get_account::<T>("Christopher");
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, authority.clone());
+
+		assert!(Authorities::<T>::contains_key(authority.clone()));
+		assert_last_event::<T>(Event::AuthorityRegistered { authority }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn delegate_authority() -> Result<(), BenchmarkError> {
+		let parent = get_account::<T>("Christopher");
+		let authority = get_account::<T>("Pavel");
+		Authorities::<T>::insert(&parent, AuthorityInfo { parent: None, depth: 0, revoked: false });
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(parent.clone()), authority.clone());
+
+		assert_eq!(Authorities::<T>::get(authority.clone()).map(|info| info.depth), Some(1));
+		assert_last_event::<T>(Event::AuthorityDelegated { parent, authority }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_authority() -> Result<(), BenchmarkError> {
+		let parent = get_account::<T>("Christopher");
+		let authority = get_account::<T>("Pavel");
+		Authorities::<T>::insert(&parent, AuthorityInfo { parent: None, depth: 0, revoked: false });
+		Authorities::<T>::insert(&authority, AuthorityInfo { parent: Some(parent.clone()), depth: 1, revoked: false });
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(parent), authority.clone());
+
+		assert_eq!(Authorities::<T>::get(authority.clone()).map(|info| info.revoked), Some(true));
+		assert_last_event::<T>(Event::AuthorityRevoked { authority }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn send_certificates(
+		n: Linear<1, { T::MaxBulkCertificates::get() }>,
+		d: Linear<0, { T::MaxDelegationDepth::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller_account: T::AccountId = get_account::<T>("Spock");
+		let recipients: Vec<T::AccountId> = (0..n).map(|i| benchmark_account("cadet", i, 0)).collect();
+
+		T::Currency::set_balance(&caller_account, BalanceOf::<T>::max_value() / 2u32.into());
+		// Make the caller an authority `d` delegations below a root, so the chain is walked
+		let mut parent = None;
+		for depth in 0..d {
+			let authority: T::AccountId = benchmark_account("admiral", depth, 0);
+			Authorities::<T>::insert(&authority, AuthorityInfo { parent: parent.take(), depth, revoked: false });
+			parent = Some(authority);
+		}
+		Authorities::<T>::insert(&caller_account, AuthorityInfo { parent, depth: d, revoked: false });
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller_account.clone()),
+			BoundedVec::try_from(recipients.clone()).unwrap(),
+			get_claim_type::<T>(),
+			T::Hash::default(),
+			Some(BlockNumberFor::<T>::max_value()),
+		);
+
+		for recipient in recipients.iter() {
+			let offer = PendingCertificates::<T>::get(caller_account.clone(), recipient.clone());
+			assert_eq!(offer.and_then(|offer| offer.authority_chain).map(|chain| chain.len()), Some(d as usize));
+		}
+		let last = recipients.last().unwrap().clone();
+		assert_last_event::<T>(Event::CertificateSent { sender: caller_account, recipient: last }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_certificates(n: Linear<1, { T::MaxBulkCertificates::get() }>) -> Result<(), BenchmarkError> {
+		let caller_account: T::AccountId = get_account::<T>("Spock");
+		let recipients: Vec<T::AccountId> = (0..n).map(|i| benchmark_account("cadet", i, 0)).collect();
+
+		T::Currency::set_balance(&caller_account, BalanceOf::<T>::max_value() / 2u32.into());
+		Certificate::<T>::send_certificates(
+			RawOrigin::Signed(caller_account.clone()).into(),
+			BoundedVec::try_from(recipients.clone()).unwrap(),
+			get_claim_type::<T>(),
+			T::Hash::default(),
+			Some(BlockNumberFor::<T>::max_value()),
+		)?;
+		for recipient in recipients.iter() {
+			Certificate::<T>::accept_certificate(RawOrigin::Signed(recipient.clone()).into(), caller_account.clone())?;
+		}
 
 		#[extrinsic_call]
-		_(caller, target.clone());
+		_(
+			RawOrigin::Signed(caller_account.clone()),
+			BoundedVec::try_from(recipients.clone()).unwrap(),
+			Some(T::Hash::default()),
+		);
 
-		let caller_account_id: T::AccountId = get_account::<T>("Spock");
-		let target_account_id: T::AccountId = get_account::<T>("Montgomery");
-		assert!(CertificateList::<T>::contains_key(caller_account_id.clone(), target_account_id.clone()));
-		assert!(!CertificateList::<T>::get(caller_account_id.clone(), target_account_id.clone()));
-		assert_last_event::<T>(Event::CertificateRevoked { sender: caller_account_id, recipient: target_account_id }.into());
+		for recipient in recipients.iter() {
+			assert!(!CertificateList::<T>::contains_key(caller_account.clone(), recipient.clone()));
+		}
+		let last = recipients.last().unwrap().clone();
+		assert_last_event::<T>(Event::CertificateRevoked { sender: caller_account, recipient: last }.into());
 		Ok(())
 	}
 
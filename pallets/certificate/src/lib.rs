@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -11,57 +13,236 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
-const CERTIFICATE_EXISTS: bool = true;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::{Get, RuntimeDebug}, BoundedVec};
+use scale_info::TypeInfo;
+
+/// The content of a certificate issued from one account to another.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxClaimTypeLength, MaxDelegationDepth))]
+#[codec(mel_bound(
+    AccountId: MaxEncodedLen,
+    BlockNumber: MaxEncodedLen,
+    Hash: MaxEncodedLen,
+    Balance: MaxEncodedLen
+))]
+pub struct CertificateInfo<
+    AccountId,
+    BlockNumber,
+    Hash,
+    Balance,
+    MaxClaimTypeLength: Get<u32>,
+    MaxDelegationDepth: Get<u32>,
+> {
+    /// Application-defined kind of claim, e.g. `course-completion` or `membership`.
+    pub claim_type: BoundedVec<u8, MaxClaimTypeLength>,
+    /// Hash of the off-chain certificate content.
+    pub content_hash: Hash,
+    /// The block in which the certificate was issued.
+    pub issued_at: BlockNumber,
+    /// The index within `issued_at` of the extrinsic that issued the certificate. `None` if
+    /// the certificate predates this record, in which case `issued_at` is the block of the
+    /// upgrade that migrated it rather than the block that issued it.
+    pub extrinsic_index: Option<u32>,
+    /// The block at which the certificate lapses, if any.
+    pub expires_at: Option<BlockNumber>,
+    /// The deposit held from the sender for this certificate.
+    pub deposit: Balance,
+    /// The authorities above the sender at the time of issuance, starting with the sender's
+    /// delegator and ending with the root authority. `None` if the sender was not a
+    /// certificate authority.
+    pub authority_chain: Option<BoundedVec<AccountId, MaxDelegationDepth>>,
+}
+
+/// A certificate authority registered by the authority origin or delegated by another
+/// authority.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AuthorityInfo<AccountId> {
+    /// The authority that delegated issuance to this one, or `None` for a root authority.
+    pub parent: Option<AccountId>,
+    /// The number of delegations between this authority and its root authority.
+    pub depth: u32,
+    /// Whether this authority has been revoked.
+    pub revoked: bool,
+}
+
+/// A compact record of a certificate that was revoked by its sender.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RevocationRecord<BlockNumber, Hash> {
+    /// The block in which the revoked certificate was issued.
+    pub issued_at: BlockNumber,
+    /// The block in which the certificate was revoked.
+    pub revoked_at: BlockNumber,
+    /// Hash of an off-chain explanation for the revocation, if any.
+    pub reason: Option<Hash>,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
-        dispatch::{DispatchResultWithPostInfo, DispatchResult},
+        dispatch::DispatchResultWithPostInfo,
         pallet_prelude::*,
-        traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
+        traits::{
+            fungible::{Inspect, Mutate, MutateHold},
+            tokens::Precision,
+        },
+        weights::WeightMeter,
     };
     use frame_system::pallet_prelude::*;
+    use frame_support::sp_runtime::traits::{Saturating, Zero};
 
-    use crate::{weights::WeightInfo, CERTIFICATE_EXISTS};
+    use crate::{weights::WeightInfo, AuthorityInfo, CertificateInfo, RevocationRecord};
 
-    type BalanceOf<T> =
-        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    pub type CertificateInfoOf<T> = CertificateInfo<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        <T as frame_system::Config>::Hash,
+        BalanceOf<T>,
+        <T as Config>::MaxClaimTypeLength,
+        <T as Config>::MaxDelegationDepth,
+    >;
+
+    pub type AuthorityChainOf<T> =
+        BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxDelegationDepth>;
+
+    pub type RevocationRecordOf<T> =
+        RevocationRecord<BlockNumberFor<T>, <T as frame_system::Config>::Hash>;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type WeightInfo: WeightInfo;
-        type Currency: LockableCurrency<
-            Self::AccountId,
-            Moment = frame_system::pallet_prelude::BlockNumberFor<Self>,
-        >;
-        type LockId: Get<LockIdentifier>;
+        /// The currency used to hold certificate deposits.
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+        /// The deposit held from the sender for each outstanding certificate.
         type LockPrice: Get<u32>;
+        /// The maximum length of a certificate's claim type.
+        type MaxClaimTypeLength: Get<u32>;
+        /// The number of revocations remembered per sender and recipient. Older records are
+        /// dropped first.
+        type MaxRevocationHistory: Get<u32>;
+        /// The number of blocks a recipient has to accept or reject a certificate before the
+        /// offer lapses.
+        type PendingPeriod: Get<BlockNumberFor<Self>>;
+        /// The origin allowed to register and revoke root certificate authorities.
+        type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The maximum number of delegations below a root certificate authority.
+        type MaxDelegationDepth: Get<u32>;
+        /// The maximum number of recipients in a single bulk issuance or revocation.
+        type MaxBulkCertificates: Get<u32>;
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// Reasons for which this pallet places a hold on an account's funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds are held as the deposit for an outstanding certificate.
+        CertificateDeposit,
+    }
+
     #[pallet::storage]
     #[pallet::getter(fn certificate_list)]
+    /// Maps a sender and a recipient to the certificate issued between them.
     pub type CertificateList<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         T::AccountId,
         Blake2_128Concat,
         T::AccountId,
-        bool,
+        CertificateInfoOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    /// Reverse index of active certificates, keyed by recipient and then sender.
+    pub type CertificatesByRecipient<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn pending_certificate)]
+    /// Certificates offered by a sender that the recipient has not yet accepted or rejected.
+    pub type PendingCertificates<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        CertificateInfoOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn authority)]
+    /// Registered certificate authorities, including revoked ones.
+    pub type Authorities<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, AuthorityInfo<T::AccountId>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn revocation_history)]
+    /// Past revocations between a sender and a recipient, oldest first.
+    pub type RevocationHistory<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<RevocationRecordOf<T>, T::MaxRevocationHistory>,
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    /// Certificates and pending offers keyed by the block at which they lapse.
+    pub type ExpiryQueue<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (T::AccountId, T::AccountId),
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    /// The next block whose expiring certificates have not been swept yet. Set to the current
+    /// block when the first expiry is scheduled, so the sweep does not start at genesis.
+    pub type NextExpiryCheck<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         CertificateSent { sender: T::AccountId, recipient: T::AccountId },
         CertificateRevoked { sender: T::AccountId, recipient: T::AccountId },
+        CertificateExpired { sender: T::AccountId, recipient: T::AccountId },
+        CertificateAccepted { sender: T::AccountId, recipient: T::AccountId },
+        CertificateRejected { sender: T::AccountId, recipient: T::AccountId },
+        CertificateOfferExpired { sender: T::AccountId, recipient: T::AccountId },
+        CertificateCancelled { sender: T::AccountId, recipient: T::AccountId },
+        AuthorityRegistered { authority: T::AccountId },
+        AuthorityDelegated { parent: T::AccountId, authority: T::AccountId },
+        AuthorityRevoked { authority: T::AccountId },
         CertificateLock { account: T::AccountId, amount: BalanceOf<T> },
         CertificateUnlock { account: T::AccountId, amount: BalanceOf<T> },
     }
@@ -71,57 +252,423 @@ pub mod pallet {
         CertificateNotOwned,
         CertificateExists,
         InsufficientBalance,
+        /// The requested expiry block is not in the future.
+        InvalidExpiry,
+        /// There is no pending certificate from the given sender.
+        CertificateNotPending,
+        /// The account is already registered as a certificate authority.
+        AuthorityExists,
+        /// The account is not a registered certificate authority.
+        NotAuthority,
+        /// The certificate authority has been revoked.
+        AuthorityRevoked,
+        /// Delegating would exceed `MaxDelegationDepth`.
+        DelegationTooDeep,
+        /// Only the delegating authority or the authority origin may revoke an authority.
+        NotAuthorityParent,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::expire_certificates(now, remaining_weight)
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(T::WeightInfo::send_certificate())]
+        #[pallet::weight(T::WeightInfo::send_certificates(1, T::MaxDelegationDepth::get()))]
         #[pallet::call_index(0)]
-        pub fn send_certificate(origin: OriginFor<T>, recipient: T::AccountId) -> DispatchResultWithPostInfo {
+        pub fn send_certificate(
+            origin: OriginFor<T>,
+            recipient: T::AccountId,
+            claim_type: BoundedVec<u8, T::MaxClaimTypeLength>,
+            content_hash: T::Hash,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            if T::Currency::total_balance(&who) < T::Currency::minimum_balance() {
-                return Err(Error::<T>::InsufficientBalance.into());
-            }
-            ensure!(
-                !CertificateList::<T>::contains_key(&who, &recipient),
-                Error::<T>::CertificateExists
-            );
-            T::Currency::set_lock(T::LockId::get(), &who, 10u32.into(), WithdrawReasons::all());
-            Self::deposit_event(Event::CertificateLock { account: who.clone(), amount: T::Currency::free_balance(&who) });
-            <CertificateList<T>>::try_mutate(
-                &who,
-                recipient.clone(),
-                |certificate| -> DispatchResult {
-                    *certificate = CERTIFICATE_EXISTS;
-                    Ok(())
-                },
-            )?;
-            Self::deposit_event(Event::CertificateSent { sender: who.clone(), recipient: recipient.clone() });
+            Self::do_send_certificates(&who, core::iter::once(recipient), claim_type, content_hash, expires_at)?;
             Ok(().into())
         }
-        #[pallet::weight(T::WeightInfo::revoke_certificate())]
+        #[pallet::weight(T::WeightInfo::revoke_certificates(1))]
         #[pallet::call_index(1)]
-        pub fn revoke_certificate(origin: OriginFor<T>, recipient: T::AccountId) -> DispatchResultWithPostInfo {
+        pub fn revoke_certificate(
+            origin: OriginFor<T>,
+            recipient: T::AccountId,
+            reason: Option<T::Hash>,
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            if T::Currency::total_balance(&who) < T::Currency::minimum_balance() {
-                return Err(Error::<T>::InsufficientBalance.into());
+            Self::do_revoke_certificates(&who, core::iter::once(recipient), reason)?;
+            Ok(().into())
+        }
+
+        /// Accepts a certificate offered to the origin by `sender`, making it active.
+        #[pallet::weight(T::WeightInfo::accept_certificate())]
+        #[pallet::call_index(2)]
+        pub fn accept_certificate(origin: OriginFor<T>, sender: T::AccountId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let certificate = <PendingCertificates<T>>::get(&sender, &who)
+                .ok_or(Error::<T>::CertificateNotPending)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            // The offer may have lapsed without the sweep having reached it yet
+            ensure!(now < Self::offer_deadline(certificate.issued_at), Error::<T>::CertificateNotPending);
+            if let Some(expiry) = certificate.expires_at {
+                ensure!(expiry > now, Error::<T>::InvalidExpiry);
             }
-            ensure!(
-                CertificateList::<T>::contains_key(&who, &recipient),
-                Error::<T>::CertificateNotOwned
-            );
-            T::Currency::remove_lock(T::LockId::get(), &who);
-            Self::deposit_event(Event::CertificateUnlock { account: who.clone(), amount: T::Currency::free_balance(&who) });
-            <CertificateList<T>>::try_mutate(
-                &who,
-                recipient.clone(),
-                |certificate| -> DispatchResult {
-                    *certificate = !CERTIFICATE_EXISTS;
-                    Ok(())
-                },
-            )?;
-            Self::deposit_event(Event::CertificateRevoked { sender: who.clone(), recipient: recipient.clone() });
+            <PendingCertificates<T>>::remove(&sender, &who);
+            <ExpiryQueue<T>>::remove(Self::offer_deadline(certificate.issued_at), (&sender, &who));
+            if let Some(expiry) = certificate.expires_at {
+                Self::schedule_expiry(expiry, &sender, &who);
+            }
+            <CertificateList<T>>::insert(&sender, &who, certificate);
+            <CertificatesByRecipient<T>>::insert(&who, &sender, ());
+            Self::deposit_event(Event::CertificateAccepted { sender, recipient: who });
+            Ok(().into())
+        }
+
+        /// Rejects a certificate offered to the origin by `sender`, releasing the sender's
+        /// deposit.
+        #[pallet::weight(T::WeightInfo::reject_certificate())]
+        #[pallet::call_index(3)]
+        pub fn reject_certificate(origin: OriginFor<T>, sender: T::AccountId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::withdraw_offer(&sender, &who)?;
+            Self::deposit_event(Event::CertificateRejected { sender, recipient: who });
+            Ok(().into())
+        }
+
+        /// Registers `authority` as a root certificate authority.
+        #[pallet::weight(T::WeightInfo::register_authority())]
+        #[pallet::call_index(4)]
+        pub fn register_authority(origin: OriginFor<T>, authority: T::AccountId) -> DispatchResultWithPostInfo {
+            T::AuthorityOrigin::ensure_origin(origin)?;
+            ensure!(!<Authorities<T>>::contains_key(&authority), Error::<T>::AuthorityExists);
+            <Authorities<T>>::insert(&authority, AuthorityInfo { parent: None, depth: 0, revoked: false });
+            Self::deposit_event(Event::AuthorityRegistered { authority });
             Ok(().into())
         }
+
+        /// Delegates certificate issuance from the origin, which must be an active authority, to
+        /// `authority`.
+        #[pallet::weight(T::WeightInfo::delegate_authority())]
+        #[pallet::call_index(5)]
+        pub fn delegate_authority(origin: OriginFor<T>, authority: T::AccountId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let parent = <Authorities<T>>::get(&who).ok_or(Error::<T>::NotAuthority)?;
+            ensure!(!parent.revoked, Error::<T>::AuthorityRevoked);
+            ensure!(!<Authorities<T>>::contains_key(&authority), Error::<T>::AuthorityExists);
+            let depth = parent.depth.saturating_add(1);
+            ensure!(depth <= T::MaxDelegationDepth::get(), Error::<T>::DelegationTooDeep);
+            <Authorities<T>>::insert(&authority, AuthorityInfo { parent: Some(who.clone()), depth, revoked: false });
+            Self::deposit_event(Event::AuthorityDelegated { parent: who, authority });
+            Ok(().into())
+        }
+
+        /// Revokes a certificate authority. Certificates issued under it no longer verify. The
+        /// authority origin may revoke any authority; a signed origin may revoke authorities it
+        /// delegated to directly.
+        #[pallet::weight(T::WeightInfo::revoke_authority())]
+        #[pallet::call_index(6)]
+        pub fn revoke_authority(origin: OriginFor<T>, authority: T::AccountId) -> DispatchResultWithPostInfo {
+            let caller = match T::AuthorityOrigin::ensure_origin(origin.clone()) {
+                Ok(_) => None,
+                Err(_) => Some(ensure_signed(origin)?),
+            };
+            <Authorities<T>>::try_mutate(&authority, |info| -> DispatchResult {
+                let info = info.as_mut().ok_or(Error::<T>::NotAuthority)?;
+                ensure!(!info.revoked, Error::<T>::AuthorityRevoked);
+                if let Some(caller) = caller {
+                    ensure!(info.parent.as_ref() == Some(&caller), Error::<T>::NotAuthorityParent);
+                }
+                info.revoked = true;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::AuthorityRevoked { authority });
+            Ok(().into())
+        }
+
+        /// Offers the same certificate to each of `recipients`, holding the combined deposit
+        /// from the origin in one go. Fails as a whole if any recipient already has a
+        /// certificate or offer from the origin.
+        #[pallet::weight(T::WeightInfo::send_certificates(
+            recipients.len() as u32,
+            T::MaxDelegationDepth::get(),
+        ))]
+        #[pallet::call_index(7)]
+        pub fn send_certificates(
+            origin: OriginFor<T>,
+            recipients: BoundedVec<T::AccountId, T::MaxBulkCertificates>,
+            claim_type: BoundedVec<u8, T::MaxClaimTypeLength>,
+            content_hash: T::Hash,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_send_certificates(&who, recipients.into_iter(), claim_type, content_hash, expires_at)?;
+            Ok(().into())
+        }
+
+        /// Revokes the origin's certificates for each of `recipients`, releasing their
+        /// deposits in one go. Fails as a whole if any of the certificates does not exist.
+        #[pallet::weight(T::WeightInfo::revoke_certificates(recipients.len() as u32))]
+        #[pallet::call_index(8)]
+        pub fn revoke_certificates(
+            origin: OriginFor<T>,
+            recipients: BoundedVec<T::AccountId, T::MaxBulkCertificates>,
+            reason: Option<T::Hash>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_revoke_certificates(&who, recipients.into_iter(), reason)?;
+            Ok(().into())
+        }
+
+        /// Withdraws a certificate the origin offered to `recipient` before it was accepted,
+        /// releasing the origin's deposit.
+        #[pallet::weight(T::WeightInfo::reject_certificate())]
+        #[pallet::call_index(9)]
+        pub fn cancel_certificate(origin: OriginFor<T>, recipient: T::AccountId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::withdraw_offer(&who, &recipient)?;
+            Self::deposit_event(Event::CertificateCancelled { sender: who, recipient });
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Records a pending offer from `who` to every recipient and holds the combined deposit.
+        fn do_send_certificates(
+            who: &T::AccountId,
+            recipients: impl ExactSizeIterator<Item = T::AccountId>,
+            claim_type: BoundedVec<u8, T::MaxClaimTypeLength>,
+            content_hash: T::Hash,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            if T::Currency::total_balance(who) < T::Currency::minimum_balance() {
+                return Err(Error::<T>::InsufficientBalance.into());
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            if let Some(expiry) = expires_at {
+                ensure!(expiry > now, Error::<T>::InvalidExpiry);
+            }
+            let deposit: BalanceOf<T> = T::LockPrice::get().into();
+            let total = deposit.saturating_mul((recipients.len() as u32).into());
+            T::Currency::hold(&HoldReason::CertificateDeposit.into(), who, total)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            Self::deposit_event(Event::CertificateLock { account: who.clone(), amount: total });
+            let authority_chain = Self::authority_chain(who)?;
+            let extrinsic_index = frame_system::Pallet::<T>::extrinsic_index();
+            let deadline = Self::offer_deadline(now);
+            for recipient in recipients {
+                ensure!(
+                    !<CertificateList<T>>::contains_key(who, &recipient) &&
+                        !<PendingCertificates<T>>::contains_key(who, &recipient),
+                    Error::<T>::CertificateExists
+                );
+                let certificate = CertificateInfo {
+                    claim_type: claim_type.clone(),
+                    content_hash,
+                    issued_at: now,
+                    extrinsic_index,
+                    expires_at,
+                    deposit,
+                    authority_chain: authority_chain.clone(),
+                };
+                <PendingCertificates<T>>::insert(who, &recipient, certificate);
+                Self::schedule_expiry(deadline, who, &recipient);
+                Self::deposit_event(Event::CertificateSent { sender: who.clone(), recipient });
+            }
+            Ok(())
+        }
+
+        /// Revokes the certificates from `who` to every recipient and releases their combined
+        /// deposit.
+        fn do_revoke_certificates(
+            who: &T::AccountId,
+            recipients: impl Iterator<Item = T::AccountId>,
+            reason: Option<T::Hash>,
+        ) -> DispatchResult {
+            if T::Currency::total_balance(who) < T::Currency::minimum_balance() {
+                return Err(Error::<T>::InsufficientBalance.into());
+            }
+            let mut total = BalanceOf::<T>::zero();
+            let mut revoked = Vec::new();
+            for recipient in recipients {
+                let certificate = <CertificateList<T>>::take(who, &recipient)
+                    .ok_or(Error::<T>::CertificateNotOwned)?;
+                <CertificatesByRecipient<T>>::remove(&recipient, who);
+                if let Some(expiry) = certificate.expires_at {
+                    <ExpiryQueue<T>>::remove(expiry, (who, &recipient));
+                }
+                total = total.saturating_add(certificate.deposit);
+                Self::record_revocation(who, &recipient, certificate.issued_at, reason);
+                revoked.push(recipient);
+            }
+            let released = Self::release_deposit(who, total)?;
+            Self::deposit_event(Event::CertificateUnlock { account: who.clone(), amount: released });
+            for recipient in revoked {
+                Self::deposit_event(Event::CertificateRevoked { sender: who.clone(), recipient });
+            }
+            Ok(())
+        }
+
+        /// Removes the pending offer from `sender` to `recipient` and releases its deposit.
+        fn withdraw_offer(sender: &T::AccountId, recipient: &T::AccountId) -> DispatchResult {
+            let certificate = <PendingCertificates<T>>::take(sender, recipient)
+                .ok_or(Error::<T>::CertificateNotPending)?;
+            <ExpiryQueue<T>>::remove(Self::offer_deadline(certificate.issued_at), (sender, recipient));
+            let released = Self::release_deposit(sender, certificate.deposit)?;
+            Self::deposit_event(Event::CertificateUnlock { account: sender.clone(), amount: released });
+            Ok(())
+        }
+
+        /// Returns the active certificate from `sender` to `recipient`, ignoring certificates
+        /// that have expired but have not been swept yet.
+        pub fn active_certificate(sender: &T::AccountId, recipient: &T::AccountId) -> Option<CertificateInfoOf<T>> {
+            let now = frame_system::Pallet::<T>::block_number();
+            <CertificateList<T>>::get(sender, recipient)
+                .filter(|certificate| certificate.expires_at.is_none_or(|expiry| expiry > now))
+        }
+
+        /// Returns whether `sender` holds an active certificate for `recipient`.
+        pub fn is_certified(sender: &T::AccountId, recipient: &T::AccountId) -> bool {
+            Self::active_certificate(sender, recipient).is_some()
+        }
+
+        /// Returns the recipients of all active certificates issued by `sender`.
+        pub fn certificates_issued_by(sender: &T::AccountId) -> Vec<T::AccountId> {
+            <CertificateList<T>>::iter_key_prefix(sender)
+                .filter(|recipient| Self::is_certified(sender, recipient))
+                .collect()
+        }
+
+        /// Returns the senders of all active certificates held by `recipient`.
+        pub fn certificates_held_by(recipient: &T::AccountId) -> Vec<T::AccountId> {
+            <CertificatesByRecipient<T>>::iter_key_prefix(recipient)
+                .filter(|sender| Self::is_certified(sender, recipient))
+                .collect()
+        }
+
+        /// Returns whether an active certificate from `sender` to `recipient` exists and, if it
+        /// was issued by a certificate authority, whether every authority in its chain is still
+        /// unrevoked.
+        pub fn verify_certificate(sender: &T::AccountId, recipient: &T::AccountId) -> bool {
+            let Some(certificate) = Self::active_certificate(sender, recipient) else {
+                return false;
+            };
+            match certificate.authority_chain {
+                None => true,
+                Some(chain) => core::iter::once(sender)
+                    .chain(chain.iter())
+                    .all(|authority| <Authorities<T>>::get(authority).is_some_and(|info| !info.revoked)),
+            }
+        }
+
+        /// Collects the authorities above `who`, or `None` if `who` is not a certificate
+        /// authority. Fails if `who` or any authority above it has been revoked.
+        fn authority_chain(
+            who: &T::AccountId,
+        ) -> Result<Option<AuthorityChainOf<T>>, DispatchError> {
+            let Some(mut info) = <Authorities<T>>::get(who) else {
+                return Ok(None);
+            };
+            let mut chain = BoundedVec::new();
+            loop {
+                ensure!(!info.revoked, Error::<T>::AuthorityRevoked);
+                let Some(parent) = info.parent else {
+                    return Ok(Some(chain));
+                };
+                info = <Authorities<T>>::get(&parent).ok_or(Error::<T>::NotAuthority)?;
+                chain.try_push(parent).map_err(|_| Error::<T>::DelegationTooDeep)?;
+            }
+        }
+
+        /// Appends a revocation to the history kept for `sender` and `recipient`, dropping the
+        /// oldest record once `MaxRevocationHistory` is reached.
+        fn record_revocation(
+            sender: &T::AccountId,
+            recipient: &T::AccountId,
+            issued_at: BlockNumberFor<T>,
+            reason: Option<T::Hash>,
+        ) {
+            let record = RevocationRecord {
+                issued_at,
+                revoked_at: frame_system::Pallet::<T>::block_number(),
+                reason,
+            };
+            <RevocationHistory<T>>::mutate(sender, recipient, |history| {
+                if T::MaxRevocationHistory::get() == 0 {
+                    return;
+                }
+                if history.is_full() {
+                    history.remove(0);
+                }
+                let _ = history.try_push(record);
+            });
+        }
+
+        /// Releases a certificate deposit previously held from `who`.
+        fn release_deposit(who: &T::AccountId, amount: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+            T::Currency::release(&HoldReason::CertificateDeposit.into(), who, amount, Precision::BestEffort)
+        }
+
+        /// Schedules the certificate or offer from `sender` to `recipient` to lapse at `block`.
+        fn schedule_expiry(block: BlockNumberFor<T>, sender: &T::AccountId, recipient: &T::AccountId) {
+            if !<NextExpiryCheck<T>>::exists() {
+                <NextExpiryCheck<T>>::put(frame_system::Pallet::<T>::block_number());
+            }
+            <ExpiryQueue<T>>::insert(block, (sender, recipient), ());
+        }
+
+        /// The block at which an offer made in block `offered_at` lapses.
+        fn offer_deadline(offered_at: BlockNumberFor<T>) -> BlockNumberFor<T> {
+            offered_at.saturating_add(T::PendingPeriod::get())
+        }
+
+        /// Removes a single expired certificate or lapsed offer and releases its deposit.
+        pub(crate) fn expire_certificate(sender: &T::AccountId, recipient: &T::AccountId) {
+            let (certificate, event) = if let Some(certificate) = <PendingCertificates<T>>::take(sender, recipient) {
+                (certificate, Event::CertificateOfferExpired { sender: sender.clone(), recipient: recipient.clone() })
+            } else if let Some(certificate) = <CertificateList<T>>::take(sender, recipient) {
+                <CertificatesByRecipient<T>>::remove(recipient, sender);
+                (certificate, Event::CertificateExpired { sender: sender.clone(), recipient: recipient.clone() })
+            } else {
+                return;
+            };
+            if let Ok(released) = Self::release_deposit(sender, certificate.deposit) {
+                Self::deposit_event(Event::CertificateUnlock { account: sender.clone(), amount: released });
+            }
+            Self::deposit_event(event);
+        }
+
+        /// Sweeps certificates that expired up to and including `now`, stopping once `limit` is
+        /// used up. Blocks that are not fully swept are resumed on the next call.
+        pub(crate) fn expire_certificates(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(limit);
+            let step = T::DbWeight::get().reads_writes(2, 1);
+            if meter.try_consume(step).is_err() {
+                return meter.consumed();
+            }
+            let Some(mut block) = NextExpiryCheck::<T>::get() else {
+                return meter.consumed();
+            };
+            while block <= now {
+                match ExpiryQueue::<T>::iter_key_prefix(block).next() {
+                    Some((sender, recipient)) => {
+                        if meter.try_consume(T::WeightInfo::expire_certificate()).is_err() {
+                            break;
+                        }
+                        ExpiryQueue::<T>::remove(block, (&sender, &recipient));
+                        Self::expire_certificate(&sender, &recipient);
+                    },
+                    None => {
+                        if meter.try_consume(T::DbWeight::get().reads(1)).is_err() {
+                            break;
+                        }
+                        block.saturating_inc();
+                    },
+                }
+            }
+            NextExpiryCheck::<T>::put(block);
+            meter.consumed()
+        }
     }
 }
@@ -0,0 +1,83 @@
+//! Storage migrations for the certificate pallet.
+
+/// Migrates certificates stored as a bare `bool` to `CertificateInfo`, and their deposits from
+/// the lock shared by all certificates of a sender to a hold per certificate.
+pub mod v1 {
+    use alloc::collections::BTreeSet;
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::{fungible::MutateHold, LockIdentifier, LockableCurrency, UncheckedOnRuntimeUpgrade},
+        BoundedVec,
+    };
+    use frame_support::sp_runtime::traits::Zero;
+
+    use crate::{
+        pallet::BalanceOf, CertificateInfo, CertificateList, CertificatesByRecipient, Config, HoldReason, Pallet,
+    };
+
+    /// Removes the `LockId` lock of every account that sent a certificate. Then turns every
+    /// certificate stored as `true` into a `CertificateInfo` issued in the block of the upgrade,
+    /// which is the earliest block it is known to exist in, with no issuing extrinsic, an empty
+    /// claim type, a default content hash, no expiry and no authority chain, holds `LockPrice` from its sender for it
+    /// and indexes it by recipient. A certificate whose deposit cannot be held is kept with a
+    /// deposit of zero. Entries stored as `false` are certificates revoked before the upgrade
+    /// and are removed.
+    pub struct UncheckedMigrateToV1<T, OldCurrency, LockId>(PhantomData<(T, OldCurrency, LockId)>);
+
+    impl<T, OldCurrency, LockId> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T, OldCurrency, LockId>
+    where
+        T: Config,
+        OldCurrency: LockableCurrency<T::AccountId>,
+        LockId: Get<LockIdentifier>,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let senders: BTreeSet<_> = <CertificateList<T>>::iter_keys().map(|(sender, _)| sender).collect();
+            for sender in senders.iter() {
+                OldCurrency::remove_lock(LockId::get(), sender);
+            }
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut entries = 0u64;
+            let mut certificates = 0u64;
+            <CertificateList<T>>::translate::<bool, _>(|sender, recipient, active| {
+                entries += 1;
+                if !active {
+                    return None;
+                }
+                certificates += 1;
+                let deposit: BalanceOf<T> = T::LockPrice::get().into();
+                let deposit = match T::Currency::hold(&HoldReason::CertificateDeposit.into(), &sender, deposit) {
+                    Ok(()) => deposit,
+                    Err(_) => Zero::zero(),
+                };
+                <CertificatesByRecipient<T>>::insert(&recipient, &sender, ());
+                Some(CertificateInfo {
+                    claim_type: BoundedVec::new(),
+                    content_hash: Default::default(),
+                    issued_at: now,
+                    extrinsic_index: None,
+                    expires_at: None,
+                    deposit,
+                    authority_chain: None,
+                })
+            });
+            let senders = senders.len() as u64;
+            // Each entry is read twice and translated; each certificate has its deposit held and
+            // is indexed by recipient; each sender has its lock removed.
+            T::DbWeight::get().reads_writes(
+                entries * 2 + certificates * 2 + senders * 2,
+                entries + certificates * 3 + senders * 2,
+            )
+        }
+    }
+
+    /// Runs `UncheckedMigrateToV1` if the pallet's storage version is 0, then sets it to 1.
+    pub type MigrateToV1<T, OldCurrency, LockId> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateToV1<T, OldCurrency, LockId>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
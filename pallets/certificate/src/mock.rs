@@ -16,7 +16,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		CertificateModule: pallet_certificate::{Pallet, Call, Storage, Event<T>},
+		CertificateModule: pallet_certificate::{Pallet, Call, Storage, Event<T>, HoldReason},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
@@ -55,7 +55,9 @@ impl system::Config for Test {
 
 parameter_types! {
 	pub const ExistentialDeposit: u128 = 1;
-	pub const MockLockIdentifier: [u8; 8] = *b"certlock";
+	pub const PendingPeriod: u64 = 10;
+	/// The lock certificate deposits were stored under before `migrations::v1`.
+	pub const CertificateLockId: [u8; 8] = *b"certlock";
 }
 
 impl pallet_balances::Config for Test {
@@ -68,7 +70,7 @@ impl pallet_balances::Config for Test {
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = System;
 	type WeightInfo = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type FreezeIdentifier = [u8; 8];
 	type MaxFreezes = ();
 	type RuntimeFreezeReason = ();
@@ -79,8 +81,14 @@ impl pallet_certificate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type Currency = Balances;
-	type LockId = MockLockIdentifier;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type LockPrice = ConstU32<10>;
+	type MaxClaimTypeLength = ConstU32<32>;
+	type MaxRevocationHistory = ConstU32<2>;
+	type PendingPeriod = PendingPeriod;
+	type AuthorityOrigin = frame_system::EnsureRoot<u64>;
+	type MaxDelegationDepth = ConstU32<2>;
+	type MaxBulkCertificates = ConstU32<3>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,14 +1,60 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use crate::{mock::*, migrations, CertificateList, CertificatesByRecipient, Error, ExpiryQueue, HoldReason, NextExpiryCheck, RevocationRecord};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchResultWithPostInfo,
+	traits::{
+		fungible::InspectHold, Currency, GetStorageVersion, Hooks, LockableCurrency, OnRuntimeUpgrade, StorageVersion,
+		WithdrawReasons,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_core::H256;
+
+fn offer(sender: u64, recipient: u64, expires_at: Option<u64>) -> DispatchResultWithPostInfo {
+	let claim_type = BoundedVec::try_from(b"membership".to_vec()).unwrap();
+	CertificateModule::send_certificate(
+		RuntimeOrigin::signed(sender),
+		recipient,
+		claim_type,
+		H256::repeat_byte(7),
+		expires_at,
+	)
+}
+
+fn issue(sender: u64, recipient: u64, expires_at: Option<u64>) -> DispatchResultWithPostInfo {
+	offer(sender, recipient, expires_at)?;
+	CertificateModule::accept_certificate(RuntimeOrigin::signed(recipient), sender)
+}
 
 #[test]
 fn test_send_certificate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
-		assert!(CertificateModule::certificate_list(1, 1));
+		assert_ok!(issue(1, 1, None));
+		System::assert_has_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
+		System::assert_last_event(crate::Event::CertificateAccepted { sender: 1, recipient: 1 }.into());
+		let certificate = CertificateModule::certificate_list(1, 1).unwrap();
+		assert_eq!(certificate.claim_type.to_vec(), b"membership".to_vec());
+		assert_eq!(certificate.content_hash, H256::repeat_byte(7));
+		assert_eq!(certificate.issued_at, 1);
+		assert_eq!(certificate.expires_at, None);
+	});
+}
+
+#[test]
+fn test_certificate_records_issuing_extrinsic() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let _ = Balances::deposit_creating(&1, 100);
+		System::set_extrinsic_index(5);
+		assert_ok!(offer(1, 2, None));
+		System::set_extrinsic_index(6);
+		assert_ok!(CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1));
+		let certificate = CertificateModule::active_certificate(&1, &2).unwrap();
+		assert_eq!(certificate.issued_at, 3);
+		assert_eq!(certificate.extrinsic_index, Some(5));
 	});
 }
 
@@ -17,13 +63,11 @@ fn test_send_existing_certificate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
-		assert_noop!(
-			CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1),
-			Error::<Test>::CertificateExists
-		);
-		assert!(CertificateModule::certificate_list(1, 1));
+		assert_ok!(issue(1, 1, None));
+		System::assert_has_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
+		System::assert_last_event(crate::Event::CertificateAccepted { sender: 1, recipient: 1 }.into());
+		assert_noop!(offer(1, 1, None), Error::<Test>::CertificateExists);
+		assert!(CertificateModule::certificate_list(1, 1).is_some());
 	});
 }
 
@@ -32,11 +76,12 @@ fn test_revoke_certificate() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
-		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 1));
+		assert_ok!(issue(1, 1, None));
+		System::assert_has_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
+		System::assert_last_event(crate::Event::CertificateAccepted { sender: 1, recipient: 1 }.into());
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 1, None));
 		System::assert_last_event(crate::Event::CertificateRevoked { sender: 1, recipient: 1 }.into());
-		assert!(!CertificateModule::certificate_list(1, 1));
+		assert!(CertificateModule::certificate_list(1, 1).is_none());
 	});
 }
 
@@ -46,12 +91,567 @@ fn test_try_revoke_unowned_certificate() {
 		System::set_block_number(1);
 		let _ = Balances::deposit_creating(&1, 100);
 		let _ = Balances::deposit_creating(&2, 100);
-		assert_ok!(CertificateModule::send_certificate(RuntimeOrigin::signed(1), 1));
-		System::assert_last_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
+		assert_ok!(issue(1, 1, None));
+		System::assert_has_event(crate::Event::CertificateSent { sender: 1, recipient: 1 }.into());
+		System::assert_last_event(crate::Event::CertificateAccepted { sender: 1, recipient: 1 }.into());
+		assert_noop!(
+			CertificateModule::revoke_certificate(RuntimeOrigin::signed(2), 1, None),
+			Error::<Test>::CertificateNotOwned
+		);
+		assert!(CertificateModule::certificate_list(1, 1).is_some());
+	});
+}
+
+#[test]
+fn test_certificate_deposits_are_held_per_certificate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		let reason: RuntimeHoldReason = HoldReason::CertificateDeposit.into();
+		assert_ok!(issue(1, 2, None));
+		System::assert_has_event(crate::Event::CertificateLock { account: 1, amount: 10 }.into());
+		assert_ok!(issue(1, 3, None));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 20);
+		assert_eq!(CertificateModule::certificate_list(1, 2).unwrap().deposit, 10);
+
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, None));
+		System::assert_has_event(crate::Event::CertificateUnlock { account: 1, amount: 10 }.into());
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+		assert_eq!(Balances::free_balance(1), 90);
+	});
+}
+
+#[test]
+fn test_send_certificate_without_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 5);
+		assert_noop!(offer(1, 2, None), Error::<Test>::InsufficientBalance);
+	});
+}
+
+#[test]
+fn test_send_certificate_with_past_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_noop!(offer(1, 2, Some(5)), Error::<Test>::InvalidExpiry);
+	});
+}
+
+#[test]
+fn test_certificate_expires_on_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		let reason: RuntimeHoldReason = HoldReason::CertificateDeposit.into();
+		assert_ok!(issue(1, 2, Some(3)));
+		assert_ok!(issue(1, 3, None));
+
+		CertificateModule::on_idle(2, Weight::MAX);
+		assert!(CertificateModule::certificate_list(1, 2).is_some());
+
+		System::set_block_number(3);
+		CertificateModule::on_idle(3, Weight::MAX);
+		System::assert_last_event(crate::Event::CertificateExpired { sender: 1, recipient: 2 }.into());
+		assert!(CertificateModule::certificate_list(1, 2).is_none());
+		assert!(CertificateModule::certificate_list(1, 3).is_some());
+		assert!(!ExpiryQueue::<Test>::contains_key(3, (1, 2)));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+	});
+}
+
+#[test]
+fn test_expiry_sweep_respects_weight_limit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(issue(1, 2, Some(2)));
+
+		CertificateModule::on_idle(2, Weight::zero());
+		assert!(CertificateModule::certificate_list(1, 2).is_some());
+
+		CertificateModule::on_idle(3, Weight::MAX);
+		assert!(CertificateModule::certificate_list(1, 2).is_none());
+	});
+}
+
+#[test]
+fn test_expiry_sweep_starts_at_first_scheduled_block() {
+	new_test_ext().execute_with(|| {
+		// Nothing is swept before the first expiry is scheduled
+		CertificateModule::on_idle(1_000_000, Weight::MAX);
+		assert_eq!(NextExpiryCheck::<Test>::get(), None);
+
+		System::set_block_number(1_000_000);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(issue(1, 2, Some(1_000_002)));
+		assert_eq!(NextExpiryCheck::<Test>::get(), Some(1_000_000));
+
+		System::set_block_number(1_000_002);
+		CertificateModule::on_idle(1_000_002, Weight::MAX);
+		assert!(CertificateModule::certificate_list(1, 2).is_none());
+		assert_eq!(NextExpiryCheck::<Test>::get(), Some(1_000_003));
+	});
+}
+
+#[test]
+fn test_revoked_certificate_leaves_expiry_queue() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(issue(1, 2, Some(10)));
+		assert!(ExpiryQueue::<Test>::contains_key(10, (1, 2)));
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, None));
+		assert!(!ExpiryQueue::<Test>::contains_key(10, (1, 2)));
+	});
+}
+
+#[test]
+fn test_revocation_is_recorded_in_history() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(issue(1, 2, None));
+		System::set_block_number(4);
+		assert_ok!(CertificateModule::revoke_certificate(
+			RuntimeOrigin::signed(1),
+			2,
+			Some(H256::repeat_byte(9))
+		));
+		assert_eq!(
+			CertificateModule::revocation_history(1, 2).to_vec(),
+			vec![RevocationRecord { issued_at: 1, revoked_at: 4, reason: Some(H256::repeat_byte(9)) }]
+		);
+	});
+}
+
+#[test]
+fn test_reissue_certificate_after_revocation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(issue(1, 2, None));
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, None));
+		System::set_block_number(2);
+		assert_ok!(issue(1, 2, None));
+		System::assert_last_event(crate::Event::CertificateAccepted { sender: 1, recipient: 2 }.into());
+		assert_eq!(CertificateModule::certificate_list(1, 2).unwrap().issued_at, 2);
+	});
+}
+
+#[test]
+fn test_revocation_history_drops_oldest_record() {
+	new_test_ext().execute_with(|| {
+		let _ = Balances::deposit_creating(&1, 100);
+		for block in 1..=3 {
+			System::set_block_number(block);
+			assert_ok!(issue(1, 2, None));
+			assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, None));
+		}
+		let history = CertificateModule::revocation_history(1, 2);
+		assert_eq!(history.len(), 2);
+		assert_eq!(history[0].revoked_at, 2);
+		assert_eq!(history[1].revoked_at, 3);
+	});
+}
+
+#[test]
+fn test_sent_certificate_is_pending_until_accepted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(offer(1, 2, Some(50)));
+		System::assert_last_event(crate::Event::CertificateSent { sender: 1, recipient: 2 }.into());
+		assert!(CertificateModule::certificate_list(1, 2).is_none());
+		assert!(CertificateModule::pending_certificate(1, 2).is_some());
+		assert_noop!(offer(1, 2, None), Error::<Test>::CertificateExists);
+		assert_noop!(
+			CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 2, None),
+			Error::<Test>::CertificateNotOwned
+		);
+
+		assert_ok!(CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(crate::Event::CertificateAccepted { sender: 1, recipient: 2 }.into());
+		assert!(CertificateModule::pending_certificate(1, 2).is_none());
+		assert!(CertificateModule::certificate_list(1, 2).is_some());
+		assert!(!ExpiryQueue::<Test>::contains_key(1 + PendingPeriod::get(), (1, 2)));
+		assert!(ExpiryQueue::<Test>::contains_key(50, (1, 2)));
+	});
+}
+
+#[test]
+fn test_only_recipient_can_accept_certificate() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(offer(1, 2, None));
+		assert_noop!(
+			CertificateModule::accept_certificate(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::CertificateNotPending
+		);
+		assert_noop!(
+			CertificateModule::reject_certificate(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::CertificateNotPending
+		);
+	});
+}
+
+#[test]
+fn test_reject_certificate_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		let reason: RuntimeHoldReason = HoldReason::CertificateDeposit.into();
+		assert_ok!(offer(1, 2, None));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+		assert_ok!(CertificateModule::reject_certificate(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(crate::Event::CertificateRejected { sender: 1, recipient: 2 }.into());
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+		assert!(CertificateModule::pending_certificate(1, 2).is_none());
+		assert!(!ExpiryQueue::<Test>::contains_key(1 + PendingPeriod::get(), (1, 2)));
+	});
+}
+
+#[test]
+fn test_cancel_certificate_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		let reason: RuntimeHoldReason = HoldReason::CertificateDeposit.into();
+		assert_ok!(offer(1, 2, None));
+		// Only the sender can cancel its offer, and only while it is pending
+		assert_noop!(
+			CertificateModule::cancel_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::CertificateNotPending
+		);
+		assert_ok!(CertificateModule::cancel_certificate(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(crate::Event::CertificateCancelled { sender: 1, recipient: 2 }.into());
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+		assert!(CertificateModule::pending_certificate(1, 2).is_none());
+		assert!(!ExpiryQueue::<Test>::contains_key(1 + PendingPeriod::get(), (1, 2)));
+		assert_noop!(
+			CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::CertificateNotPending
+		);
+
+		assert_ok!(issue(1, 2, None));
+		assert_noop!(
+			CertificateModule::cancel_certificate(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::CertificateNotPending
+		);
+	});
+}
+
+#[test]
+fn test_pending_certificate_lapses_on_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		let reason: RuntimeHoldReason = HoldReason::CertificateDeposit.into();
+		assert_ok!(offer(1, 2, None));
+		let deadline = 1 + PendingPeriod::get();
+
+		CertificateModule::on_idle(deadline - 1, Weight::MAX);
+		assert!(CertificateModule::pending_certificate(1, 2).is_some());
+
+		System::set_block_number(deadline);
+		CertificateModule::on_idle(deadline, Weight::MAX);
+		System::assert_last_event(crate::Event::CertificateOfferExpired { sender: 1, recipient: 2 }.into());
+		assert!(CertificateModule::pending_certificate(1, 2).is_none());
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 0);
+		assert_noop!(
+			CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::CertificateNotPending
+		);
+	});
+}
+
+#[test]
+fn test_lapsed_offer_cannot_be_accepted_before_the_sweep() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(offer(1, 2, None));
+		let deadline = 1 + PendingPeriod::get();
+
+		// No `on_idle` runs, as when blocks are full and the sweep falls behind
+		System::set_block_number(deadline);
+		assert!(CertificateModule::pending_certificate(1, 2).is_some());
+		assert_noop!(
+			CertificateModule::accept_certificate(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::CertificateNotPending
+		);
+
+		// The offer is still accepted up to the block before its deadline
+		System::set_block_number(1);
+		assert_ok!(offer(1, 3, None));
+		System::set_block_number(deadline - 1);
+		assert_ok!(CertificateModule::accept_certificate(RuntimeOrigin::signed(3), 1));
+	});
+}
+
+#[test]
+fn test_register_authority_requires_root() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CertificateModule::register_authority(RuntimeOrigin::signed(1), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(CertificateModule::register_authority(RuntimeOrigin::root(), 1));
+		System::assert_last_event(crate::Event::AuthorityRegistered { authority: 1 }.into());
+		assert_noop!(
+			CertificateModule::register_authority(RuntimeOrigin::root(), 1),
+			Error::<Test>::AuthorityExists
+		);
+	});
+}
+
+#[test]
+fn test_delegation_respects_depth_limit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CertificateModule::delegate_authority(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::NotAuthority
+		);
+		assert_ok!(CertificateModule::register_authority(RuntimeOrigin::root(), 1));
+		assert_ok!(CertificateModule::delegate_authority(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(crate::Event::AuthorityDelegated { parent: 1, authority: 2 }.into());
+		assert_ok!(CertificateModule::delegate_authority(RuntimeOrigin::signed(2), 3));
+		assert_eq!(CertificateModule::authority(3).unwrap().depth, 2);
+		assert_noop!(
+			CertificateModule::delegate_authority(RuntimeOrigin::signed(3), 4),
+			Error::<Test>::DelegationTooDeep
+		);
+	});
+}
+
+#[test]
+fn test_certificate_records_authority_chain() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&3, 100);
+		let _ = Balances::deposit_creating(&5, 100);
+		assert_ok!(CertificateModule::register_authority(RuntimeOrigin::root(), 1));
+		assert_ok!(CertificateModule::delegate_authority(RuntimeOrigin::signed(1), 2));
+		assert_ok!(CertificateModule::delegate_authority(RuntimeOrigin::signed(2), 3));
+		assert_ok!(issue(3, 4, None));
+		assert_eq!(
+			CertificateModule::certificate_list(3, 4).unwrap().authority_chain.map(|chain| chain.to_vec()),
+			Some(vec![2, 1])
+		);
+		assert!(CertificateModule::verify_certificate(&3, &4));
+
+		assert_ok!(issue(5, 4, None));
+		assert_eq!(CertificateModule::certificate_list(5, 4).unwrap().authority_chain, None);
+		assert!(CertificateModule::verify_certificate(&5, &4));
+		assert!(!CertificateModule::verify_certificate(&4, &5));
+	});
+}
+
+#[test]
+fn test_revoked_authority_link_fails_verification() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&3, 100);
+		assert_ok!(CertificateModule::register_authority(RuntimeOrigin::root(), 1));
+		assert_ok!(CertificateModule::delegate_authority(RuntimeOrigin::signed(1), 2));
+		assert_ok!(CertificateModule::delegate_authority(RuntimeOrigin::signed(2), 3));
+		assert_ok!(issue(3, 4, None));
+
+		assert_noop!(
+			CertificateModule::revoke_authority(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::NotAuthorityParent
+		);
+		assert_ok!(CertificateModule::revoke_authority(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(crate::Event::AuthorityRevoked { authority: 2 }.into());
+		assert!(!CertificateModule::verify_certificate(&3, &4));
+		assert!(CertificateModule::certificate_list(3, 4).is_some());
+		assert_noop!(offer(3, 5, None), Error::<Test>::AuthorityRevoked);
+		assert_noop!(
+			CertificateModule::delegate_authority(RuntimeOrigin::signed(2), 6),
+			Error::<Test>::AuthorityRevoked
+		);
+	});
+}
+
+#[test]
+fn test_root_can_revoke_any_authority() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CertificateModule::register_authority(RuntimeOrigin::root(), 1));
+		assert_ok!(CertificateModule::delegate_authority(RuntimeOrigin::signed(1), 2));
+		assert_ok!(CertificateModule::revoke_authority(RuntimeOrigin::root(), 1));
+		assert!(CertificateModule::authority(1).unwrap().revoked);
+		assert_noop!(
+			CertificateModule::revoke_authority(RuntimeOrigin::root(), 1),
+			Error::<Test>::AuthorityRevoked
+		);
+	});
+}
+
+#[test]
+fn test_certificate_queries_use_active_certificates() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		let _ = Balances::deposit_creating(&2, 100);
+		assert_ok!(issue(1, 3, None));
+		assert_ok!(issue(1, 4, Some(5)));
+		assert_ok!(issue(2, 3, None));
+		assert_ok!(offer(2, 4, None));
+
+		assert!(CertificateModule::is_certified(&1, &3));
+		assert!(!CertificateModule::is_certified(&2, &4));
+		let mut issued = CertificateModule::certificates_issued_by(&1);
+		issued.sort();
+		assert_eq!(issued, vec![3, 4]);
+		let mut held = CertificateModule::certificates_held_by(&3);
+		held.sort();
+		assert_eq!(held, vec![1, 2]);
+		assert_eq!(CertificateModule::certificates_held_by(&4), vec![1]);
+
+		System::set_block_number(5);
+		assert!(!CertificateModule::is_certified(&1, &4));
+		assert_eq!(CertificateModule::certificates_held_by(&4), Vec::<u64>::new());
+
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 3, None));
+		assert_eq!(CertificateModule::certificates_held_by(&3), vec![2]);
+		assert!(!crate::CertificatesByRecipient::<Test>::contains_key(3, 1));
+	});
+}
+
+#[test]
+fn test_expired_certificate_leaves_recipient_index() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(issue(1, 2, Some(3)));
+		assert!(crate::CertificatesByRecipient::<Test>::contains_key(2, 1));
+		CertificateModule::on_idle(3, Weight::MAX);
+		assert!(!crate::CertificatesByRecipient::<Test>::contains_key(2, 1));
+	});
+}
+
+fn bulk_offer(sender: u64, recipients: Vec<u64>) -> DispatchResultWithPostInfo {
+	CertificateModule::send_certificates(
+		RuntimeOrigin::signed(sender),
+		BoundedVec::try_from(recipients).unwrap(),
+		BoundedVec::try_from(b"membership".to_vec()).unwrap(),
+		H256::repeat_byte(7),
+		None,
+	)
+}
+
+#[test]
+fn test_send_certificates_holds_combined_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		let reason: RuntimeHoldReason = HoldReason::CertificateDeposit.into();
+		assert_ok!(bulk_offer(1, vec![2, 3, 4]));
+		System::assert_has_event(crate::Event::CertificateLock { account: 1, amount: 30 }.into());
+		for recipient in [2, 3, 4] {
+			System::assert_has_event(crate::Event::CertificateSent { sender: 1, recipient }.into());
+			assert_eq!(CertificateModule::pending_certificate(1, recipient).unwrap().deposit, 10);
+		}
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 30);
+
+		assert_ok!(CertificateModule::reject_certificate(RuntimeOrigin::signed(3), 1));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 20);
+	});
+}
+
+#[test]
+fn test_send_certificates_is_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		assert_ok!(offer(1, 3, None));
+		assert_noop!(bulk_offer(1, vec![2, 3]), Error::<Test>::CertificateExists);
+		assert_noop!(bulk_offer(1, vec![2, 2]), Error::<Test>::CertificateExists);
+		assert!(CertificateModule::pending_certificate(1, 2).is_none());
+
+		let _ = Balances::deposit_creating(&5, 25);
+		assert_noop!(bulk_offer(5, vec![2, 3, 4]), Error::<Test>::InsufficientBalance);
+	});
+}
+
+#[test]
+fn test_revoke_certificates_releases_combined_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let _ = Balances::deposit_creating(&1, 100);
+		let reason: RuntimeHoldReason = HoldReason::CertificateDeposit.into();
+		assert_ok!(bulk_offer(1, vec![2, 3, 4]));
+		for recipient in [2, 3, 4] {
+			assert_ok!(CertificateModule::accept_certificate(RuntimeOrigin::signed(recipient), 1));
+		}
+		let recipients = BoundedVec::try_from(vec![2, 4]).unwrap();
+		assert_ok!(CertificateModule::revoke_certificates(RuntimeOrigin::signed(1), recipients, None));
+		System::assert_has_event(crate::Event::CertificateUnlock { account: 1, amount: 20 }.into());
+		System::assert_has_event(crate::Event::CertificateRevoked { sender: 1, recipient: 2 }.into());
+		System::assert_last_event(crate::Event::CertificateRevoked { sender: 1, recipient: 4 }.into());
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+		assert_eq!(CertificateModule::certificates_issued_by(&1), vec![3]);
+		assert_eq!(CertificateModule::revocation_history(1, 4).len(), 1);
+
+		let recipients = BoundedVec::try_from(vec![3, 4]).unwrap();
 		assert_noop!(
-			CertificateModule::revoke_certificate(RuntimeOrigin::signed(2), 1),
+			CertificateModule::revoke_certificates(RuntimeOrigin::signed(1), recipients, None),
 			Error::<Test>::CertificateNotOwned
 		);
-		assert!(CertificateModule::certificate_list(1, 1));
+		assert!(CertificateModule::is_certified(&1, &3));
+	});
+}
+
+#[test]
+fn test_migration_translates_certificates_and_moves_locks_to_holds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(7);
+		StorageVersion::new(0).put::<CertificateModule>();
+		let _ = Balances::deposit_creating(&1, 100);
+		let _ = Balances::deposit_creating(&4, 15);
+		// Certificates stored as a bare `bool`, `false` once revoked, behind one lock per sender
+		for (sender, recipient, active) in [(1u64, 2u64, true), (1, 3, false), (1, 5, true), (4, 2, true), (4, 3, true)] {
+			frame_support::storage::unhashed::put(&CertificateList::<Test>::hashed_key_for(sender, recipient), &active);
+			Balances::set_lock(CertificateLockId::get(), &sender, 10, WithdrawReasons::all());
+		}
+
+		migrations::v1::MigrateToV1::<Test, Balances, CertificateLockId>::on_runtime_upgrade();
+
+		assert_eq!(CertificateModule::on_chain_storage_version(), StorageVersion::new(1));
+		let certificate = CertificateModule::certificate_list(1, 2).unwrap();
+		assert!(certificate.claim_type.is_empty());
+		assert_eq!(certificate.issued_at, 7);
+		assert_eq!(certificate.extrinsic_index, None);
+		assert_eq!(certificate.expires_at, None);
+		assert_eq!(certificate.authority_chain, None);
+		assert!(CertificateModule::certificate_list(1, 3).is_none());
+		assert!(CertificateModule::is_certified(&4, &2));
+		assert!(CertificatesByRecipient::<Test>::contains_key(2, 1));
+		assert!(!CertificatesByRecipient::<Test>::contains_key(3, 1));
+		assert_eq!(CertificateModule::certificates_held_by(&2).len(), 2);
+		assert!(Balances::locks(&1).is_empty());
+		assert!(Balances::locks(&4).is_empty());
+		let reason: RuntimeHoldReason = HoldReason::CertificateDeposit.into();
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 20);
+		assert_eq!(certificate.deposit, 10);
+		// Only one of the deposits of account 4 could be held
+		assert_eq!(Balances::balance_on_hold(&reason, &4), 10);
+		let mut deposits = [2, 3].map(|recipient| CertificateModule::certificate_list(4, recipient).unwrap().deposit);
+		deposits.sort();
+		assert_eq!(deposits, [0, 10]);
+
+		// Revoking releases exactly the certificate's share
+		assert_ok!(CertificateModule::revoke_certificate(RuntimeOrigin::signed(1), 5, None));
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+
+		// Running the migration again changes nothing
+		migrations::v1::MigrateToV1::<Test, Balances, CertificateLockId>::on_runtime_upgrade();
+		assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+		assert_eq!(CertificateModule::certificate_list(1, 2).map(|certificate| certificate.issued_at), Some(7));
 	});
 }
@@ -1,13 +1,11 @@
 
-//! Autogenerated weights for `pallet_certificate`
+//! Weights for `pallet_certificate`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
-//! DATE: 2025-05-11, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `DESKTOP-AH3IE72`, CPU: `AMD Ryzen 3 3100 4-Core Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! NONE OF THESE WEIGHTS HAS BEEN BENCHMARKED YET. They are hand-written estimates derived from
+//! the storage each call accesses, listed above each function. Replace this file with the output
+//! of the command below, run against the benchmarks in `benchmarking.rs`, before relying on them.
 
-// Executed Command:
+// Command to regenerate this file:
 // frame-omni-bencher
 // v1
 // benchmark
@@ -17,7 +15,7 @@
 // --pallet
 // pallet_certificate
 // --extrinsic
-// 
+// *
 // --template
 // /home/neurosx/WORKING_WORKSPACE/StandaloneSolochain2503/solochain/pallets/benchmarking/frame-weight-template.hbs
 // --output
@@ -33,83 +31,197 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_certificate`.
 pub trait WeightInfo {
-	fn send_certificate() -> Weight;
-	fn revoke_certificate() -> Weight;
+	fn expire_certificate() -> Weight;
+	fn accept_certificate() -> Weight;
+	fn reject_certificate() -> Weight;
+	fn register_authority() -> Weight;
+	fn delegate_authority() -> Weight;
+	fn revoke_authority() -> Weight;
+	fn send_certificates(n: u32, d: u32, ) -> Weight;
+	fn revoke_certificates(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_certificate` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryQueue` (r:1 w:1)
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn send_certificate() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `146`
-		//  Estimated: `4764`
-		// Minimum execution time: 52_995_000 picoseconds.
-		Weight::from_parts(57_410_000, 4764)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn expire_certificate() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(43_120_000, 3674)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Storage: `Certificate::ExpiryQueue` (r:0 w:2)
+	/// Storage: `Certificate::CertificateList` (r:0 w:1)
+	fn accept_certificate() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(22_310_000, 3674)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Storage: `Certificate::ExpiryQueue` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn revoke_certificate() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `330`
-		//  Estimated: `4764`
-		// Minimum execution time: 55_212_000 picoseconds.
-		Weight::from_parts(56_405_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+	fn reject_certificate() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(42_050_000, 3674)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Certificate::Authorities` (r:1 w:1)
+	fn register_authority() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(11_760_000, 3551)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Certificate::Authorities` (r:2 w:1)
+	fn delegate_authority() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(15_990_000, 6112)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Certificate::Authorities` (r:1 w:1)
+	fn revoke_authority() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(13_640_000, 3551)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Certificate::Authorities` (r:5 w:0)
+	/// Storage: `Certificate::CertificateList` (r:256 w:0)
+	/// Storage: `Certificate::PendingCertificates` (r:256 w:256)
+	/// Storage: `Certificate::ExpiryQueue` (r:0 w:256)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `d` is `[0, 4]`.
+	fn send_certificates(n: u32, d: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(44_902_133, 3593)
+			.saturating_add(Weight::from_parts(17_246_118, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2684).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(d.into()))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Certificate::CertificateList` (r:256 w:256)
+	/// Storage: `Certificate::RevocationHistory` (r:256 w:256)
+	/// Storage: `Certificate::ExpiryQueue` (r:0 w:256)
+	/// Storage: `Certificate::CertificatesByRecipient` (r:0 w:256)
+	/// The range of component `n` is `[1, 256]`.
+	fn revoke_certificates(n: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(41_377_540, 3593)
+			.saturating_add(Weight::from_parts(20_734_902, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2848).saturating_mul(n.into()))
 	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Certificate::ExpiryQueue` (r:1 w:1)
 	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn send_certificate() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `146`
-		//  Estimated: `4764`
-		// Minimum execution time: 52_995_000 picoseconds.
-		Weight::from_parts(57_410_000, 4764)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn expire_certificate() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(43_120_000, 3674)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Storage: `Certificate::ExpiryQueue` (r:0 w:2)
+	/// Storage: `Certificate::CertificateList` (r:0 w:1)
+	fn accept_certificate() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(22_310_000, 3674)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Certificate::PendingCertificates` (r:1 w:1)
+	/// Storage: `Certificate::ExpiryQueue` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Certificate::CertificateList` (r:1 w:1)
-	/// Proof: `Certificate::CertificateList` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn revoke_certificate() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `330`
-		//  Estimated: `4764`
-		// Minimum execution time: 55_212_000 picoseconds.
-		Weight::from_parts(56_405_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	fn reject_certificate() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(42_050_000, 3674)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Certificate::Authorities` (r:1 w:1)
+	fn register_authority() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(11_760_000, 3551)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Certificate::Authorities` (r:2 w:1)
+	fn delegate_authority() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(15_990_000, 6112)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Certificate::Authorities` (r:1 w:1)
+	fn revoke_authority() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(13_640_000, 3551)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Certificate::Authorities` (r:5 w:0)
+	/// Storage: `Certificate::CertificateList` (r:256 w:0)
+	/// Storage: `Certificate::PendingCertificates` (r:256 w:256)
+	/// Storage: `Certificate::ExpiryQueue` (r:0 w:256)
+	/// The range of component `n` is `[1, 256]`.
+	/// The range of component `d` is `[0, 4]`.
+	fn send_certificates(n: u32, d: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(44_902_133, 3593)
+			.saturating_add(Weight::from_parts(17_246_118, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2684).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(d.into()))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Certificate::CertificateList` (r:256 w:256)
+	/// Storage: `Certificate::RevocationHistory` (r:256 w:256)
+	/// Storage: `Certificate::ExpiryQueue` (r:0 w:256)
+	/// Storage: `Certificate::CertificatesByRecipient` (r:0 w:256)
+	/// The range of component `n` is `[1, 256]`.
+	fn revoke_certificates(n: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(41_377_540, 3593)
+			.saturating_add(Weight::from_parts(20_734_902, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2848).saturating_mul(n.into()))
 	}
 }
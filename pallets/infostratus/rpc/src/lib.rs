@@ -0,0 +1,257 @@
+//! RPC interface for the Infostratus pallet.
+//!
+//! Listings are paginated with opaque cursors. A page carries a `nextCursor` when more entries
+//! follow it; passing that cursor back returns the next page.
+
+use std::sync::Arc;
+
+use codec::{Decode, Encode};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use pallet_infostratus_runtime_api::{Assignment, OpenSubmission};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+
+pub use pallet_infostratus_runtime_api::InfostratusApi as InfostratusRuntimeApi;
+
+/// The largest number of entries returned in one page.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// One page of a paginated listing.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+	/// The entries of this page.
+	pub items: Vec<T>,
+	/// The cursor of the next page, or `None` if this is the last one.
+	pub next_cursor: Option<Bytes>,
+}
+
+/// A submission waiting for an assignee.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenSubmissionEntry<AccountId> {
+	/// The account that posted the submission.
+	pub poster: AccountId,
+	/// The location of the submitted resource.
+	pub resource_location: Bytes,
+	/// Title of the submission, with invalid UTF-8 replaced.
+	pub title: String,
+	/// Tags of the submission, with invalid UTF-8 replaced.
+	pub tags: Vec<String>,
+	/// The bounty paid to the assignee on approval, as a decimal string.
+	pub bounty: String,
+	/// The block in which the submission was created.
+	pub created_at: u64,
+}
+
+/// A submission an account is assigned to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssignmentEntry<AccountId> {
+	/// The account that posted the submission.
+	pub poster: AccountId,
+	/// The location of the submitted resource.
+	pub resource_location: Bytes,
+}
+
+#[rpc(client, server)]
+pub trait InfostratusApi<BlockHash, AccountId> {
+	/// Returns a page of at most `limit` submissions waiting for an assignee, starting at
+	/// `cursor` or at the beginning of the work queue.
+	#[method(name = "infostratus_openSubmissions")]
+	fn open_submissions(
+		&self,
+		cursor: Option<Bytes>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<OpenSubmissionEntry<AccountId>>>;
+
+	/// Returns a page of at most `limit` submissions `assignee` is assigned to, starting at
+	/// `cursor` or at the first assignment.
+	#[method(name = "infostratus_assignmentsOf")]
+	fn assignments_of(
+		&self,
+		assignee: AccountId,
+		cursor: Option<Bytes>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<AssignmentEntry<AccountId>>>;
+}
+
+/// Provides RPC methods to query Infostratus submissions.
+pub struct Infostratus<C, B, Balance> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<(B, Balance)>,
+}
+
+impl<C, B, Balance> Infostratus<C, B, Balance> {
+	/// Creates a new instance of the Infostratus Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The provided cursor was not returned by this api.
+	InvalidCursor,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidCursor => 2,
+		}
+	}
+}
+
+fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+}
+
+/// Decodes the position a cursor points after.
+fn decode_cursor<K: Decode>(cursor: Option<Bytes>) -> RpcResult<Option<K>> {
+	cursor
+		.map(|cursor| {
+			K::decode(&mut &cursor[..]).map_err(|e| {
+				ErrorObject::owned(Error::InvalidCursor.into(), "Invalid cursor.", Some(e.to_string()))
+			})
+		})
+		.transpose()
+}
+
+/// Clamps a requested page size to `1..=MAX_PAGE_SIZE`.
+fn page_size(limit: Option<u32>) -> u32 {
+	limit.unwrap_or(MAX_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE)
+}
+
+/// Builds a page from up to `limit + 1` fetched entries. The extra entry only signals that
+/// another page follows, whose cursor is the key of the last entry kept.
+fn paginate<T, K: Encode>(mut entries: Vec<T>, limit: u32, key: impl Fn(&T) -> K) -> (Vec<T>, Option<Bytes>) {
+	if entries.len() <= limit as usize {
+		return (entries, None);
+	}
+	entries.truncate(limit as usize);
+	let cursor = entries.last().map(|entry| Bytes(key(entry).encode()));
+	(entries, cursor)
+}
+
+impl<C, Block, AccountId, Balance> InfostratusApiServer<<Block as BlockT>::Hash, AccountId>
+	for Infostratus<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: InfostratusRuntimeApi<Block, AccountId, Balance, NumberFor<Block>>,
+	AccountId: Encode + Decode + Clone + Send + Sync + 'static,
+	Balance: Encode + Decode + ToString + Send + Sync + 'static,
+	NumberFor<Block>: Into<u64>,
+{
+	fn open_submissions(
+		&self,
+		cursor: Option<Bytes>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<OpenSubmissionEntry<AccountId>>> {
+		let start_after = decode_cursor::<(AccountId, Vec<u8>)>(cursor)?;
+		let limit = page_size(limit);
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let submissions = self
+			.client
+			.runtime_api()
+			.open_submissions(at_hash, start_after, limit + 1)
+			.map_err(|e| map_err(e, "Unable to query open submissions."))?;
+		let (submissions, next_cursor) = paginate(submissions, limit, |submission: &OpenSubmission<_, _, _>| {
+			(submission.poster.clone(), submission.resource_location.clone())
+		});
+		let items = submissions
+			.into_iter()
+			.map(|submission| OpenSubmissionEntry {
+				poster: submission.poster,
+				resource_location: Bytes(submission.resource_location),
+				title: String::from_utf8_lossy(&submission.title).into_owned(),
+				tags: submission.tags.iter().map(|tag| String::from_utf8_lossy(tag).into_owned()).collect(),
+				bounty: submission.bounty.to_string(),
+				created_at: submission.created_at.into(),
+			})
+			.collect();
+		Ok(Page { items, next_cursor })
+	}
+
+	fn assignments_of(
+		&self,
+		assignee: AccountId,
+		cursor: Option<Bytes>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<AssignmentEntry<AccountId>>> {
+		let start_after = decode_cursor::<Vec<u8>>(cursor)?;
+		let limit = page_size(limit);
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let assignments = self
+			.client
+			.runtime_api()
+			.assignments_of(at_hash, assignee, start_after, limit + 1)
+			.map_err(|e| map_err(e, "Unable to query assignments."))?;
+		let (assignments, next_cursor) =
+			paginate(assignments, limit, |assignment: &Assignment<_>| assignment.resource_location.clone());
+		let items = assignments
+			.into_iter()
+			.map(|assignment| AssignmentEntry {
+				poster: assignment.poster,
+				resource_location: Bytes(assignment.resource_location),
+			})
+			.collect();
+		Ok(Page { items, next_cursor })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn last_page_has_no_cursor() {
+		assert_eq!(paginate(vec![1u32, 2, 3], 3, |entry| *entry), (vec![1, 2, 3], None));
+		assert_eq!(paginate(Vec::<u32>::new(), 3, |entry| *entry), (vec![], None));
+	}
+
+	#[test]
+	fn full_page_points_after_its_last_entry() {
+		let (entries, cursor) = paginate(vec![1u32, 2, 3, 4], 3, |entry| *entry);
+		assert_eq!(entries, vec![1, 2, 3]);
+		assert_eq!(decode_cursor::<u32>(cursor).unwrap(), Some(3));
+	}
+
+	#[test]
+	fn malformed_cursor_is_rejected() {
+		assert!(decode_cursor::<(u64, Vec<u8>)>(Some(Bytes(vec![1, 2]))).is_err());
+		assert_eq!(decode_cursor::<u32>(None).unwrap(), None);
+	}
+
+	#[test]
+	fn page_size_is_clamped() {
+		assert_eq!(page_size(None), MAX_PAGE_SIZE);
+		assert_eq!(page_size(Some(0)), 1);
+		assert_eq!(page_size(Some(MAX_PAGE_SIZE + 1)), MAX_PAGE_SIZE);
+	}
+
+	#[test]
+	fn page_serializes_in_camel_case() {
+		let page = Page { items: vec![AssignmentEntry { poster: 1u64, resource_location: Bytes(vec![0xab]) }], next_cursor: None };
+		assert_eq!(
+			serde_json::to_value(&page).unwrap(),
+			serde_json::json!({ "items": [{ "poster": 1, "resourceLocation": "0xab" }], "nextCursor": null })
+		);
+	}
+}
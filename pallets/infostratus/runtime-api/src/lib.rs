@@ -0,0 +1,59 @@
+//! Runtime API definition for the Infostratus pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+
+/// A submission waiting for an assignee.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct OpenSubmission<AccountId, Balance, BlockNumber> {
+    /// The account that posted the submission.
+    pub poster: AccountId,
+    /// The location of the submitted resource.
+    pub resource_location: Vec<u8>,
+    /// Human-readable title of the submission.
+    pub title: Vec<u8>,
+    /// Tags under which the submission is indexed.
+    pub tags: Vec<Vec<u8>>,
+    /// The bounty paid to the assignee on approval.
+    pub bounty: Balance,
+    /// The block in which the submission was created.
+    pub created_at: BlockNumber,
+}
+
+/// A submission an account is assigned to.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct Assignment<AccountId> {
+    /// The account that posted the submission.
+    pub poster: AccountId,
+    /// The location of the submitted resource.
+    pub resource_location: Vec<u8>,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait InfostratusApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns up to `limit` open submissions, continuing after the submission posted by
+        /// `start_after.0` at `start_after.1`, or from the first one if `start_after` is `None`.
+        fn open_submissions(
+            start_after: Option<(AccountId, Vec<u8>)>,
+            limit: u32,
+        ) -> Vec<OpenSubmission<AccountId, Balance, BlockNumber>>;
+
+        /// Returns up to `limit` submissions `assignee` is assigned to, continuing after the one
+        /// at resource location `start_after`, or from the first one if it is `None`.
+        fn assignments_of(
+            assignee: AccountId,
+            start_after: Option<Vec<u8>>,
+            limit: u32,
+        ) -> Vec<Assignment<AccountId>>;
+    }
+}
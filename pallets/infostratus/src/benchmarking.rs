@@ -2,27 +2,70 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
+use alloc::{vec, vec::Vec};
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
 use sp_runtime::BoundedVec;
-use frame_support::traits::Currency;
-use crate::pallet::BalanceOf;
-use sp_runtime::traits::Bounded;
+use frame_support::traits::{fungible::{Inspect, Mutate}, EnsureOrigin, Get};
+use crate::pallet::{BalanceOf, SubmissionMetadataOf};
+use sp_runtime::traits::{Bounded, Saturating, Zero};
+
+fn bounty<T: Config>() -> BalanceOf<T> {
+    1_000_000u32.into()
+}
+
+fn metadata<T: Config>(tags: u32) -> SubmissionMetadataOf<T> {
+    let mut content_hash = vec![0x12, 0x20];
+    content_hash.extend([0xab; 32]);
+    SubmissionMetadata {
+        title: BoundedVec::try_from(vec![b't'; T::MaxTitleLength::get() as usize]).unwrap(),
+        content_hash: BoundedVec::try_from(content_hash).unwrap(),
+        mime_type: BoundedVec::try_from(vec![b'm'; T::MaxMimeTypeLength::get() as usize]).unwrap(),
+        tags: BoundedVec::try_from(
+            (0..tags).map(|i| BoundedVec::try_from(i.to_be_bytes().to_vec()).unwrap()).collect::<Vec<_>>(),
+        )
+        .unwrap(),
+    }
+}
+
+fn assigned_submission<T: Config>() -> (T::AccountId, T::AccountId, BoundedVec<u8, T::MaxSize>) {
+    let poster: T::AccountId = account("poster", 0, 0);
+    let assignee: T::AccountId = whitelisted_caller();
+    let resource = BoundedVec::<u8, T::MaxSize>::try_from(b"BENCHMARK_RESOURCE".to_vec()).unwrap();
+    T::Currency::set_balance(&poster, BalanceOf::<T>::max_value() / 4u32.into());
+    T::Currency::set_balance(&assignee, BalanceOf::<T>::max_value() / 4u32.into());
+    T::ReviewerEligibility::make_eligible(&poster, &assignee);
+    Pallet::<T>::create_submission_entry(RawOrigin::Signed(poster.clone()).into(), resource.clone(), metadata::<T>(0), Some(bounty::<T>()))
+        .unwrap();
+    Pallet::<T>::request_submission_assignment(RawOrigin::Signed(assignee.clone()).into(), poster.clone(), resource.clone())
+        .unwrap();
+    (poster, assignee, resource)
+}
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
-    fn create_submission_entry() {
+    fn create_submission_entry(t: Linear<0, { T::MaxTags::get() }>) {
         let caller: T::AccountId = whitelisted_caller();
         let resource = BoundedVec::<u8, T::MaxSize>::try_from(b"BENCHMARK_RESOURCE".to_vec()).unwrap();
         // Ensure caller has enough balance
-        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 4u32.into());
+        // Resubmit a withdrawn submission so that its tags have to be unindexed as well
+        Pallet::<T>::create_submission_entry(
+            RawOrigin::Signed(caller.clone()).into(),
+            resource.clone(),
+            metadata::<T>(t),
+            None,
+        )
+        .unwrap();
+        Pallet::<T>::withdraw_submission(RawOrigin::Signed(caller.clone()).into(), resource.clone()).unwrap();
         #[extrinsic_call]
-        create_submission_entry(RawOrigin::Signed(caller.clone()), resource.clone());
+        create_submission_entry(RawOrigin::Signed(caller.clone()), resource.clone(), metadata::<T>(t), None);
         // Assert storage
         assert!(SubmissionsList::<T>::contains_key(&caller, &resource));
+        assert_eq!(SubmissionMetadataList::<T>::get(&caller, &resource).map(|m| m.tags.len() as u32), Some(t));
     }
 
     #[benchmark]
@@ -31,10 +74,23 @@ mod benchmarks {
         let assignee: T::AccountId = whitelisted_caller();
         let resource = BoundedVec::<u8, T::MaxSize>::try_from(b"BENCHMARK_RESOURCE".to_vec()).unwrap();
         // Ensure both have enough balance
-        T::Currency::make_free_balance_be(&poster, BalanceOf::<T>::max_value());
-        T::Currency::make_free_balance_be(&assignee, BalanceOf::<T>::max_value());
+        T::Currency::set_balance(&poster, BalanceOf::<T>::max_value() / 4u32.into());
+        T::Currency::set_balance(&assignee, BalanceOf::<T>::max_value() / 4u32.into());
         // Poster creates submission
-        SubmissionsList::<T>::insert(&poster, &resource, false);
+        SubmissionsList::<T>::insert(&poster, &resource, SubmissionInfo {
+            status: SubmissionStatus::Open,
+            assignee: None,
+            deposit: Zero::zero(),
+            assignee_deposit: Zero::zero(),
+            bounty: Zero::zero(),
+            dispute_deadline: None,
+            created_at: frame_system::Pallet::<T>::block_number(),
+            expires_at: frame_system::Pallet::<T>::block_number().saturating_add(T::SubmissionTtl::get()),
+            assignment_deadline: None,
+        });
+        // Require a certificate issuer so that eligibility is checked in full
+        ReviewerIssuers::<T>::insert(&poster, &resource, account::<T::AccountId>("issuer", 0, 0));
+        T::ReviewerEligibility::make_eligible(&poster, &assignee);
         #[extrinsic_call]
         request_submission_assignment(RawOrigin::Signed(assignee.clone()), poster.clone(), resource.clone());
         // Assert storage
@@ -42,5 +98,188 @@ mod benchmarks {
         assert!(SubmissionsList::<T>::contains_key(&poster, &resource));
     }
 
+    #[benchmark]
+    fn withdraw_submission() {
+        let caller: T::AccountId = whitelisted_caller();
+        let resource = BoundedVec::<u8, T::MaxSize>::try_from(b"BENCHMARK_RESOURCE".to_vec()).unwrap();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 4u32.into());
+        Pallet::<T>::create_submission_entry(
+            RawOrigin::Signed(caller.clone()).into(),
+            resource.clone(),
+            metadata::<T>(0),
+            None,
+        )
+        .unwrap();
+        #[extrinsic_call]
+        withdraw_submission(RawOrigin::Signed(caller.clone()), resource.clone());
+        assert_eq!(
+            SubmissionsList::<T>::get(&caller, &resource).map(|submission| submission.status),
+            Some(SubmissionStatus::Withdrawn)
+        );
+    }
+
+    #[benchmark]
+    fn complete_assignment() {
+        let (poster, assignee, resource) = assigned_submission::<T>();
+        #[extrinsic_call]
+        complete_assignment(RawOrigin::Signed(assignee), resource.clone());
+        assert_eq!(
+            SubmissionsList::<T>::get(&poster, &resource).map(|submission| submission.status),
+            Some(SubmissionStatus::InReview)
+        );
+    }
+
+    #[benchmark]
+    fn release_assignment() {
+        let (poster, assignee, resource) = assigned_submission::<T>();
+        #[extrinsic_call]
+        release_assignment(RawOrigin::Signed(assignee.clone()), resource.clone());
+        assert!(!AssignmentsList::<T>::contains_key(&assignee, &resource));
+        assert_eq!(
+            SubmissionsList::<T>::get(&poster, &resource).map(|submission| submission.status),
+            Some(SubmissionStatus::Open)
+        );
+    }
+
+    #[benchmark]
+    fn review_submission() {
+        let (poster, assignee, resource) = assigned_submission::<T>();
+        Pallet::<T>::complete_assignment(RawOrigin::Signed(assignee.clone()).into(), resource.clone()).unwrap();
+        #[extrinsic_call]
+        review_submission(RawOrigin::Signed(poster.clone()), resource.clone(), true);
+        assert!(!AssignmentsList::<T>::contains_key(&assignee, &resource));
+        assert_eq!(
+            SubmissionsList::<T>::get(&poster, &resource).map(|submission| submission.status),
+            Some(SubmissionStatus::Completed)
+        );
+    }
+
+    #[benchmark]
+    fn dispute_submission() {
+        let (poster, assignee, resource) = assigned_submission::<T>();
+        Pallet::<T>::complete_assignment(RawOrigin::Signed(assignee.clone()).into(), resource.clone()).unwrap();
+        #[extrinsic_call]
+        dispute_submission(RawOrigin::Signed(assignee), poster.clone(), resource.clone());
+        assert_eq!(
+            SubmissionsList::<T>::get(&poster, &resource).map(|submission| submission.status),
+            Some(SubmissionStatus::Disputed)
+        );
+    }
+
+    #[benchmark]
+    fn resolve_dispute() -> Result<(), BenchmarkError> {
+        let origin = T::DisputeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let (poster, assignee, resource) = assigned_submission::<T>();
+        Pallet::<T>::complete_assignment(RawOrigin::Signed(assignee.clone()).into(), resource.clone()).unwrap();
+        Pallet::<T>::dispute_submission(RawOrigin::Signed(assignee.clone()).into(), poster.clone(), resource.clone())
+            .unwrap();
+        #[extrinsic_call]
+        resolve_dispute(origin as T::RuntimeOrigin, poster.clone(), resource.clone(), true);
+        assert!(!AssignmentsList::<T>::contains_key(&assignee, &resource));
+        assert_eq!(
+            SubmissionsList::<T>::get(&poster, &resource).map(|submission| submission.status),
+            Some(SubmissionStatus::Completed)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn settle_submission() {
+        let (poster, assignee, resource) = assigned_submission::<T>();
+        Pallet::<T>::complete_assignment(RawOrigin::Signed(assignee.clone()).into(), resource.clone()).unwrap();
+        Pallet::<T>::review_submission(RawOrigin::Signed(poster.clone()).into(), resource.clone(), false).unwrap();
+        let deadline = frame_system::Pallet::<T>::block_number() + T::DisputePeriod::get();
+        frame_system::Pallet::<T>::set_block_number(deadline + 1u32.into());
+        #[extrinsic_call]
+        settle_submission(RawOrigin::Signed(assignee), poster.clone(), resource.clone());
+        assert!(SubmissionsList::<T>::get(&poster, &resource).is_some_and(|submission| submission.is_closed()));
+    }
+
+    #[benchmark]
+    fn configure_review() {
+        let (poster, _, resource) = assigned_submission::<T>();
+        let slots = T::MaxReviewers::get();
+        #[extrinsic_call]
+        configure_review(RawOrigin::Signed(poster.clone()), resource.clone(), slots, slots);
+        assert_eq!(ReviewPanels::<T>::get(&poster, &resource).map(|panel| panel.slots), Some(slots));
+    }
+
+    #[benchmark]
+    fn submit_verdict() {
+        let (poster, assignee, resource) = assigned_submission::<T>();
+        let slots = T::MaxReviewers::get();
+        Pallet::<T>::configure_review(RawOrigin::Signed(poster.clone()).into(), resource.clone(), slots, slots)
+            .unwrap();
+        Pallet::<T>::complete_assignment(RawOrigin::Signed(assignee.clone()).into(), resource.clone()).unwrap();
+        // Fill all but one slot so that the last verdict reaches the quorum and pays the bounty
+        for i in 1..slots {
+            let reviewer: T::AccountId = account("reviewer", i, 0);
+            T::Currency::set_balance(&reviewer, T::Currency::minimum_balance());
+            T::ReviewerEligibility::make_eligible(&poster, &reviewer);
+            Pallet::<T>::submit_verdict(RawOrigin::Signed(reviewer).into(), poster.clone(), resource.clone(), true, None)
+                .unwrap();
+        }
+        let reviewer: T::AccountId = account("reviewer", 0, 0);
+        T::Currency::set_balance(&reviewer, T::Currency::minimum_balance());
+        T::ReviewerEligibility::make_eligible(&poster, &reviewer);
+        ReviewerIssuers::<T>::insert(&poster, &resource, account::<T::AccountId>("issuer", 0, 0));
+        let comment_hash = metadata::<T>(0).content_hash;
+        #[extrinsic_call]
+        submit_verdict(RawOrigin::Signed(reviewer), poster.clone(), resource.clone(), true, Some(comment_hash));
+        assert_eq!(
+            SubmissionsList::<T>::get(&poster, &resource).map(|submission| submission.status),
+            Some(SubmissionStatus::Completed)
+        );
+    }
+
+    #[benchmark]
+    fn set_reviewer_issuer() {
+        let (poster, _, resource) = assigned_submission::<T>();
+        let issuer: T::AccountId = account("issuer", 0, 0);
+        #[extrinsic_call]
+        set_reviewer_issuer(RawOrigin::Signed(poster.clone()), resource.clone(), Some(issuer.clone()));
+        assert_eq!(ReviewerIssuers::<T>::get(&poster, &resource), Some(issuer));
+    }
+
+    #[benchmark]
+    fn expire_submission() {
+        let caller: T::AccountId = whitelisted_caller();
+        let resource = BoundedVec::<u8, T::MaxSize>::try_from(b"BENCHMARK_RESOURCE".to_vec()).unwrap();
+        T::Currency::set_balance(&caller, BalanceOf::<T>::max_value() / 4u32.into());
+        Pallet::<T>::create_submission_entry(
+            RawOrigin::Signed(caller.clone()).into(),
+            resource.clone(),
+            metadata::<T>(0),
+            Some(bounty::<T>()),
+        )
+        .unwrap();
+        let expires_at = SubmissionsList::<T>::get(&caller, &resource).unwrap().expires_at;
+        #[block]
+        {
+            Pallet::<T>::expire(expires_at, &caller, &resource).unwrap();
+        }
+        assert_eq!(
+            SubmissionsList::<T>::get(&caller, &resource).map(|submission| submission.status),
+            Some(SubmissionStatus::Expired)
+        );
+    }
+
+    #[benchmark]
+    fn expire_assignment() {
+        let (poster, assignee, resource) = assigned_submission::<T>();
+        let deadline = SubmissionsList::<T>::get(&poster, &resource).unwrap().assignment_deadline.unwrap();
+        // Lapse the assignment after the time-to-live so that the submission is rescheduled
+        frame_system::Pallet::<T>::set_block_number(deadline.saturating_add(T::SubmissionTtl::get()));
+        #[block]
+        {
+            Pallet::<T>::expire(deadline, &poster, &resource).unwrap();
+        }
+        assert!(!AssignmentsList::<T>::contains_key(&assignee, &resource));
+        assert_eq!(
+            SubmissionsList::<T>::get(&poster, &resource).map(|submission| submission.status),
+            Some(SubmissionStatus::Open)
+        );
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -11,71 +13,426 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
-const ASSIGNMENT_EXISTS: bool = true;
-const ASSIGNMENT_DOES_NOT_EXIST: bool = false;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::{Get, RuntimeDebug},
+    BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+
+/// Decides which accounts may work on or review the submissions of a poster.
+pub trait ReviewerEligibility<AccountId> {
+    /// Returns whether `reviewer` may take on or give a verdict on a submission posted by
+    /// `poster`, who may require reviewers to be certified by `issuer`.
+    fn is_eligible(poster: &AccountId, reviewer: &AccountId, issuer: Option<&AccountId>) -> bool;
+
+    /// Makes `reviewer` eligible for the submissions of `poster` in benchmarks.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn make_eligible(_poster: &AccountId, _reviewer: &AccountId) {}
+}
+
+/// Allows every account to work on and review every submission.
+impl<AccountId> ReviewerEligibility<AccountId> for () {
+    fn is_eligible(_poster: &AccountId, _reviewer: &AccountId, _issuer: Option<&AccountId>) -> bool {
+        true
+    }
+}
+
+/// The stage a submission has reached.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SubmissionStatus {
+    /// Waiting for an assignee.
+    Open,
+    /// Being worked on by an assignee.
+    Assigned,
+    /// Completed by the assignee and awaiting the poster's review.
+    InReview,
+    /// Approved by the poster.
+    Completed,
+    /// Turned down by the poster after review.
+    Rejected,
+    /// Withdrawn by the poster before it was assigned.
+    Withdrawn,
+    /// Escalated by the poster or the assignee to the dispute origin.
+    Disputed,
+    /// Expired before anyone took it on.
+    Expired,
+}
+
+impl SubmissionStatus {
+    /// Returns whether the submission can no longer change.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Completed | Self::Rejected | Self::Withdrawn | Self::Expired)
+    }
+}
+
+/// The state of a submission posted for review.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SubmissionInfo<AccountId, Balance, BlockNumber> {
+    /// The stage the submission has reached.
+    pub status: SubmissionStatus,
+    /// The account working on or having worked on the submission, if any.
+    pub assignee: Option<AccountId>,
+    /// The deposit held from the poster while the submission is outstanding.
+    pub deposit: Balance,
+    /// The deposit held from the assignee while its assignment is outstanding.
+    pub assignee_deposit: Balance,
+    /// The bounty held from the poster and not yet paid out or refunded.
+    pub bounty: Balance,
+    /// The block until which a rejection may be disputed before the bounty is refunded.
+    pub dispute_deadline: Option<BlockNumber>,
+    /// The block in which the submission was created.
+    pub created_at: BlockNumber,
+    /// The block at which the submission expires if it is still waiting for an assignee.
+    pub expires_at: BlockNumber,
+    /// The block at which the current assignment lapses unless its work has been handed in.
+    pub assignment_deadline: Option<BlockNumber>,
+}
+
+impl<AccountId, Balance, BlockNumber> SubmissionInfo<AccountId, Balance, BlockNumber> {
+    /// Returns whether the submission has ended and no bounty is left to settle.
+    pub fn is_closed(&self) -> bool {
+        self.status.is_final() && self.dispute_deadline.is_none()
+    }
+}
+
+/// Descriptive metadata recorded by the poster alongside a submission.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxTitleLength, MaxContentHashLength, MaxMimeTypeLength, MaxTagLength, MaxTags))]
+#[codec(mel_bound())]
+pub struct SubmissionMetadata<
+    MaxTitleLength: Get<u32>,
+    MaxContentHashLength: Get<u32>,
+    MaxMimeTypeLength: Get<u32>,
+    MaxTagLength: Get<u32>,
+    MaxTags: Get<u32>,
+> {
+    /// Human-readable title of the submission.
+    pub title: BoundedVec<u8, MaxTitleLength>,
+    /// Multihash of the submitted resource, against which reviewers can check the content
+    /// retrieved from the resource location.
+    pub content_hash: BoundedVec<u8, MaxContentHashLength>,
+    /// MIME type of the submitted resource, e.g. `text/html`.
+    pub mime_type: BoundedVec<u8, MaxMimeTypeLength>,
+    /// Distinct tags under which the submission is indexed.
+    pub tags: BoundedVec<BoundedVec<u8, MaxTagLength>, MaxTags>,
+}
+
+/// A reviewer's verdict on the completed work of a submission.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Verdict<AccountId, CommentHash> {
+    /// The account that gave the verdict.
+    pub reviewer: AccountId,
+    /// Whether the reviewer approved the work.
+    pub approve: bool,
+    /// Multihash of an off-chain comment explaining the verdict, if any.
+    pub comment_hash: Option<CommentHash>,
+}
+
+/// The reviewers a poster asked to judge the completed work of a submission in their place.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxReviewers))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, CommentHash: MaxEncodedLen))]
+pub struct ReviewPanel<AccountId, CommentHash, MaxReviewers: Get<u32>> {
+    /// The number of reviewers that may give a verdict.
+    pub slots: u32,
+    /// The number of matching verdicts that decides the review.
+    pub quorum: u32,
+    /// The verdicts given so far, in the order they were submitted.
+    pub verdicts: BoundedVec<Verdict<AccountId, CommentHash>, MaxReviewers>,
+}
+
+impl<AccountId, CommentHash, MaxReviewers: Get<u32>> ReviewPanel<AccountId, CommentHash, MaxReviewers> {
+    /// Returns the number of approving and rejecting verdicts.
+    pub fn tally(&self) -> (u32, u32) {
+        let approvals = self.verdicts.iter().filter(|verdict| verdict.approve).count() as u32;
+        (approvals, self.verdicts.len() as u32 - approvals)
+    }
+}
+
+/// Reads an unsigned varint from the front of `bytes`, returning it and the remaining bytes.
+fn read_varint(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, &bytes[i + 1..]));
+        }
+    }
+    None
+}
+
+/// Returns whether `bytes` is a well-formed multihash: a varint hash function code and a varint
+/// digest length, followed by a non-empty digest of exactly that length.
+pub fn is_valid_multihash(bytes: &[u8]) -> bool {
+    let Some((_, rest)) = read_varint(bytes) else {
+        return false;
+    };
+    let Some((length, digest)) = read_varint(rest) else {
+        return false;
+    };
+    length != 0 && digest.len() as u64 == length
+}
 
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
-        dispatch::{DispatchResultWithPostInfo, DispatchResult},
+        dispatch::DispatchResult,
         pallet_prelude::*,
-        traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
+        storage::with_storage_layer,
+        traits::{
+            Defensive,
+            fungible::{Inspect, Mutate, MutateHold},
+            tokens::{Fortitude, Precision, Restriction},
+        },
+    };
+    use frame_support::{
+        sp_runtime::traits::{Saturating, Zero},
+        weights::WeightMeter,
     };
     use frame_system::pallet_prelude::*;
+    use alloc::vec::Vec;
 
-    use crate::{weights::WeightInfo, ASSIGNMENT_DOES_NOT_EXIST, ASSIGNMENT_EXISTS};
+    use crate::{
+        is_valid_multihash, weights::WeightInfo, ReviewPanel, ReviewerEligibility, SubmissionInfo,
+        SubmissionMetadata, SubmissionStatus, Verdict,
+    };
 
     pub type BalanceOf<T> =
-        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    pub type SubmissionInfoOf<T> =
+        SubmissionInfo<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+    pub type SubmissionMetadataOf<T> = SubmissionMetadata<
+        <T as Config>::MaxTitleLength,
+        <T as Config>::MaxContentHashLength,
+        <T as Config>::MaxMimeTypeLength,
+        <T as Config>::MaxTagLength,
+        <T as Config>::MaxTags,
+    >;
+
+    pub type TagOf<T> = BoundedVec<u8, <T as Config>::MaxTagLength>;
+
+    pub type ResourceLocationOf<T> = BoundedVec<u8, <T as Config>::MaxSize>;
+
+    pub type ContentHashOf<T> = BoundedVec<u8, <T as Config>::MaxContentHashLength>;
+
+    pub type ReviewPanelOf<T> =
+        ReviewPanel<<T as frame_system::Config>::AccountId, ContentHashOf<T>, <T as Config>::MaxReviewers>;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type WeightInfo: WeightInfo;
-        type Currency: LockableCurrency<
-            Self::AccountId,
-            Moment = frame_system::pallet_prelude::BlockNumberFor<Self>,
-        >;
+        /// The currency used to hold submission deposits and bounties.
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
         type MaxSize: Get<u32>;
-        type LockId: Get<LockIdentifier>;
+        /// The deposit held from the poster of each outstanding submission and from the assignee
+        /// of each outstanding assignment.
         type LockPrice: Get<u32>;
+        /// The number of blocks after a rejection during which either party may dispute it.
+        type DisputePeriod: Get<BlockNumberFor<Self>>;
+        /// The origin that settles disputed submissions.
+        type DisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The number of blocks a submission waits for an assignee before it expires.
+        type SubmissionTtl: Get<BlockNumberFor<Self>>;
+        /// The number of blocks an assignee has to hand in their work before the assignment
+        /// lapses and the submission is open again.
+        type AssignmentPeriod: Get<BlockNumberFor<Self>>;
+        /// The maximum length of a submission title.
+        type MaxTitleLength: Get<u32>;
+        /// The maximum length of a submission's content multihash.
+        type MaxContentHashLength: Get<u32>;
+        /// The maximum length of a submission's MIME type.
+        type MaxMimeTypeLength: Get<u32>;
+        /// The maximum length of a single tag.
+        type MaxTagLength: Get<u32>;
+        /// The maximum number of tags on a submission.
+        type MaxTags: Get<u32>;
+        /// The maximum number of reviewer slots a poster may open on a submission.
+        type MaxReviewers: Get<u32>;
+        /// Decides who may take on and review submissions. `()` allows everyone.
+        type ReviewerEligibility: ReviewerEligibility<Self::AccountId>;
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// Reasons for which this pallet places a hold on an account's funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds are held as the deposit for an outstanding submission or assignment.
+        SubmissionDeposit,
+        /// Funds are held as the bounty for a submission.
+        SubmissionBounty,
+    }
+
     #[pallet::storage]
     #[pallet::getter(fn assignments_list)]
+    /// Maps an assignee and a resource location to the poster of the submission it is working
+    /// on.
     pub type AssignmentsList<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         T::AccountId,
         Blake2_128Concat,
         BoundedVec<u8, T::MaxSize>,
-        bool,
-        ValueQuery,
+        T::AccountId,
+        OptionQuery,
     >;
 
     #[pallet::storage]
     #[pallet::getter(fn submissions_list)]
+    /// Maps a poster and a resource location to the state of the submission.
     pub type SubmissionsList<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         T::AccountId,
         Blake2_128Concat,
         BoundedVec<u8, T::MaxSize>,
-        bool,
-        ValueQuery,
+        SubmissionInfoOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    /// Indexes the submissions waiting for an assignee by poster and resource location.
+    pub type OpenSubmissions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxSize>,
+        (),
+        OptionQuery,
     >;
 
+    #[pallet::storage]
+    /// Schedules submissions and assignments to expire, keyed by the block at which they do and
+    /// then by poster and resource location. Entries whose submission has moved on since are
+    /// skipped when swept.
+    pub type ExpiryQueue<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        (T::AccountId, BoundedVec<u8, T::MaxSize>),
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    /// The first block of `ExpiryQueue` that has not been swept completely.
+    pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn submission_metadata)]
+    /// Maps a poster and a resource location to the metadata of its latest submission.
+    pub type SubmissionMetadataList<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxSize>,
+        SubmissionMetadataOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn review_panels)]
+    /// Maps a poster and a resource location to the review panel of the submission, if the
+    /// poster opened one.
+    pub type ReviewPanels<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxSize>,
+        ReviewPanelOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn reviewer_issuers)]
+    /// Maps a poster and a resource location to the certificate issuer the poster requires
+    /// reviewers of the submission to be certified by.
+    pub type ReviewerIssuers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxSize>,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    /// Indexes submissions by tag, keyed by tag and then by poster and resource location.
+    pub type SubmissionsByTag<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TagOf<T>,
+        Blake2_128Concat,
+        (T::AccountId, BoundedVec<u8, T::MaxSize>),
+        (),
+        OptionQuery,
+    >;
+
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         SubmissionSent { who: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize> },
         SubmissionAssigned { resource_location: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
+        SubmissionWithdrawn { who: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize> },
+        AssignmentCompleted { resource_location: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
+        AssignmentReleased { resource_location: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
+        SubmissionApproved { poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize> },
+        SubmissionRejected { poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize> },
+        SubmissionDisputed { who: T::AccountId, poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize> },
+        DisputeResolved { poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize>, approved: bool },
+        BountyEscrowed { poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize>, amount: BalanceOf<T> },
+        BountyPaid { assignee: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize>, amount: BalanceOf<T> },
+        BountyRefunded { poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize>, amount: BalanceOf<T> },
+        ReviewPanelConfigured { poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize>, slots: u32, quorum: u32 },
+        VerdictSubmitted { reviewer: T::AccountId, poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize>, approve: bool },
+        /// A review panel reached its quorum, or ran out of slots without one, in which case
+        /// `approved` is `None` and the submission is disputed.
+        ReviewConcluded {
+            poster: T::AccountId,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+            approvals: u32,
+            rejections: u32,
+            approved: Option<bool>,
+        },
+        ReviewerIssuerSet { poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize>, issuer: Option<T::AccountId> },
+        /// An open submission reached its time-to-live; its deposit was released and its bounty
+        /// refunded.
+        SubmissionExpired { poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize> },
+        /// An assignee missed their deadline; their deposit was released and the submission is
+        /// open again.
+        AssignmentExpired { poster: T::AccountId, resource_location: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
         InfostratusLock { account: T::AccountId, amount: BalanceOf<T> },
         InfostratusUnlock { account: T::AccountId, amount: BalanceOf<T> },
     }
@@ -87,78 +444,757 @@ pub mod pallet {
         SubmissionAlreadyAssigned,
         InsufficientBalance,
         CannotAssignOwnSubmission,
+        /// The submission is not in a state that allows this action.
+        InvalidSubmissionStatus,
+        /// The origin is not assigned to the submission.
+        NotAssignee,
+        /// Only the poster or the assignee of a submission may dispute it.
+        NotParticipant,
+        /// The dispute window of the submission has closed.
+        DisputeWindowClosed,
+        /// The dispute window of the submission is still open.
+        DisputeWindowOpen,
+        /// The content hash is not a well-formed multihash.
+        InvalidContentHash,
+        /// The same tag was given more than once.
+        DuplicateTag,
+        /// The review panel has more slots than allowed, or its quorum is not a majority of
+        /// its slots.
+        InvalidReviewPanel,
+        /// The submission is reviewed by a panel rather than by its poster.
+        ReviewedByPanel,
+        /// The submission has no review panel.
+        NoReviewPanel,
+        /// Every slot of the review panel has been taken.
+        ReviewSlotsFull,
+        /// The origin has already given a verdict on the submission.
+        AlreadyReviewed,
+        /// The poster and the assignee of a submission cannot sit on its review panel.
+        ReviewerIsParticipant,
+        /// The origin is not eligible to work on or review the submission.
+        NotEligible,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_expiries(now, remaining_weight)
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(T::WeightInfo::create_submission_entry())]
+        /// Posts a submission for the resource at `resource_location` together with its
+        /// metadata, optionally attaching a bounty. A resource location whose previous
+        /// submission has closed may be submitted again, replacing its metadata. The submission
+        /// expires if nobody takes it on within `SubmissionTtl` blocks.
+        #[pallet::weight(T::WeightInfo::create_submission_entry(metadata.tags.len() as u32))]
         #[pallet::call_index(0)]
         pub fn create_submission_entry(
             origin: OriginFor<T>,
             resource_location: BoundedVec<u8, T::MaxSize>,
-        ) -> DispatchResultWithPostInfo {
+            metadata: SubmissionMetadataOf<T>,
+            bounty: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             if T::Currency::total_balance(&who) < T::Currency::minimum_balance() {
                 return Err(Error::<T>::InsufficientBalance.into());
             }
             ensure!(
-                !SubmissionsList::<T>::contains_key(&who, &resource_location),
+                SubmissionsList::<T>::get(&who, &resource_location).is_none_or(|submission| submission.is_closed()),
                 Error::<T>::SubmissionExists
             );
-            T::Currency::set_lock(T::LockId::get(), &who, 10u32.into(), WithdrawReasons::all());
-            Self::deposit_event(Event::InfostratusLock { account: who.clone(), amount: T::Currency::free_balance(&who) });
-            <SubmissionsList<T>>::try_mutate(
+            ensure!(is_valid_multihash(&metadata.content_hash), Error::<T>::InvalidContentHash);
+            ensure!(
+                !metadata.tags.iter().enumerate().any(|(i, tag)| metadata.tags[..i].contains(tag)),
+                Error::<T>::DuplicateTag
+            );
+            let bounty = bounty.unwrap_or_default();
+            if !bounty.is_zero() {
+                T::Currency::hold(&HoldReason::SubmissionBounty.into(), &who, bounty)
+                    .map_err(|_| Error::<T>::InsufficientBalance)?;
+                Self::deposit_event(Event::BountyEscrowed {
+                    poster: who.clone(),
+                    resource_location: resource_location.clone(),
+                    amount: bounty,
+                });
+            }
+            let deposit = Self::hold_deposit(&who)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            let expires_at = now.saturating_add(T::SubmissionTtl::get());
+            <SubmissionsList<T>>::insert(
                 &who,
-                resource_location.clone(),
-                |value| -> DispatchResult {
-                    *value = ASSIGNMENT_DOES_NOT_EXIST;
-                    Ok(())
+                &resource_location,
+                SubmissionInfo {
+                    status: SubmissionStatus::Open,
+                    assignee: None,
+                    deposit,
+                    assignee_deposit: Zero::zero(),
+                    bounty,
+                    dispute_deadline: None,
+                    created_at: now,
+                    expires_at,
+                    assignment_deadline: None,
                 },
-            )?;
+            );
+            Self::schedule_expiry(expires_at, &who, &resource_location);
+            Self::record_metadata(&who, &resource_location, metadata);
+            <OpenSubmissions<T>>::insert(&who, &resource_location, ());
+            <ReviewPanels<T>>::remove(&who, &resource_location);
+            <ReviewerIssuers<T>>::remove(&who, &resource_location);
             Self::deposit_event(Event::SubmissionSent { who, resource_location });
-            Ok(().into())
+            Ok(())
         }
 
+        /// Takes on an open submission, holding a deposit from the origin until the assignment
+        /// ends. The assignment lapses if its work is not handed in within `AssignmentPeriod`
+        /// blocks.
         #[pallet::weight(T::WeightInfo::request_submission_assignment())]
         #[pallet::call_index(1)]
         pub fn request_submission_assignment(
             origin: OriginFor<T>,
             poster: T::AccountId,
             resource_location: BoundedVec<u8, T::MaxSize>,
-        ) -> DispatchResultWithPostInfo {
+        ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             if T::Currency::total_balance(&who) < T::Currency::minimum_balance() {
                 return Err(Error::<T>::InsufficientBalance.into());
             }
             ensure!(who != poster, Error::<T>::CannotAssignOwnSubmission);
+            Self::ensure_eligible(&poster, &resource_location, &who)?;
+            let mut submission = SubmissionsList::<T>::get(&poster, &resource_location)
+                .ok_or(Error::<T>::SubmissionDoesNotExist)?;
+            ensure!(!submission.status.is_final(), Error::<T>::InvalidSubmissionStatus);
+            ensure!(submission.status == SubmissionStatus::Open, Error::<T>::SubmissionAlreadyAssigned);
             ensure!(
-                SubmissionsList::<T>::contains_key(&poster, &resource_location),
-                Error::<T>::SubmissionDoesNotExist
+                !AssignmentsList::<T>::contains_key(&who, &resource_location),
+                Error::<T>::SubmissionAlreadyAssigned
             );
+            submission.assignee_deposit = Self::hold_deposit(&who)?;
+            <AssignmentsList<T>>::insert(&who, &resource_location, &poster);
+            <OpenSubmissions<T>>::remove(&poster, &resource_location);
+            let deadline = frame_system::Pallet::<T>::block_number().saturating_add(T::AssignmentPeriod::get());
+            Self::schedule_expiry(deadline, &poster, &resource_location);
+            submission.status = SubmissionStatus::Assigned;
+            submission.assignee = Some(who.clone());
+            submission.assignment_deadline = Some(deadline);
+            <SubmissionsList<T>>::insert(&poster, &resource_location, submission);
+            Self::deposit_event(Event::SubmissionAssigned { resource_location, who });
+            Ok(())
+        }
+
+        /// Withdraws an open submission posted by the origin, releasing its deposit and refunding
+        /// its bounty.
+        #[pallet::weight(T::WeightInfo::withdraw_submission())]
+        #[pallet::call_index(2)]
+        pub fn withdraw_submission(
+            origin: OriginFor<T>,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let (deposit, bounty) = <SubmissionsList<T>>::try_mutate(&who, &resource_location, |submission| {
+                let submission = submission.as_mut().ok_or(Error::<T>::SubmissionDoesNotExist)?;
+                ensure!(submission.status == SubmissionStatus::Open, Error::<T>::InvalidSubmissionStatus);
+                submission.status = SubmissionStatus::Withdrawn;
+                Ok::<_, DispatchError>((
+                    core::mem::take(&mut submission.deposit),
+                    core::mem::take(&mut submission.bounty),
+                ))
+            })?;
+            <OpenSubmissions<T>>::remove(&who, &resource_location);
+            Self::refund_bounty(&who, &resource_location, bounty)?;
+            Self::release_deposit(&who, deposit)?;
+            Self::deposit_event(Event::SubmissionWithdrawn { who, resource_location });
+            Ok(())
+        }
+
+        /// Marks the submission the origin is assigned to as completed, handing it to the poster
+        /// for review.
+        #[pallet::weight(T::WeightInfo::complete_assignment())]
+        #[pallet::call_index(3)]
+        pub fn complete_assignment(
+            origin: OriginFor<T>,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let poster = AssignmentsList::<T>::get(&who, &resource_location).ok_or(Error::<T>::NotAssignee)?;
+            <SubmissionsList<T>>::try_mutate(&poster, &resource_location, |submission| -> DispatchResult {
+                let submission = submission.as_mut().ok_or(Error::<T>::SubmissionDoesNotExist)?;
+                ensure!(submission.status == SubmissionStatus::Assigned, Error::<T>::InvalidSubmissionStatus);
+                submission.status = SubmissionStatus::InReview;
+                submission.assignment_deadline = None;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::AssignmentCompleted { resource_location, who });
+            Ok(())
+        }
+
+        /// Gives up the origin's assignment, reopening the submission for other assignees and
+        /// releasing the origin's deposit.
+        #[pallet::weight(T::WeightInfo::release_assignment())]
+        #[pallet::call_index(4)]
+        pub fn release_assignment(
+            origin: OriginFor<T>,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let poster = AssignmentsList::<T>::get(&who, &resource_location).ok_or(Error::<T>::NotAssignee)?;
+            let (deposit, expires_at) = <SubmissionsList<T>>::try_mutate(&poster, &resource_location, |submission| {
+                let submission = submission.as_mut().ok_or(Error::<T>::SubmissionDoesNotExist)?;
+                ensure!(submission.status == SubmissionStatus::Assigned, Error::<T>::InvalidSubmissionStatus);
+                submission.status = SubmissionStatus::Open;
+                submission.assignee = None;
+                submission.assignment_deadline = None;
+                Ok::<_, DispatchError>((core::mem::take(&mut submission.assignee_deposit), submission.expires_at))
+            })?;
+            <AssignmentsList<T>>::remove(&who, &resource_location);
+            Self::reopen(&poster, &resource_location, expires_at);
+            Self::release_deposit(&who, deposit)?;
+            Self::deposit_event(Event::AssignmentReleased { resource_location, who });
+            Ok(())
+        }
+
+        /// Approves or rejects the completed work on a submission posted by the origin, ending
+        /// the submission and releasing the deposits of the poster and the assignee. Approval pays
+        /// the bounty to the assignee. After a rejection the bounty stays held for
+        /// `DisputePeriod` blocks, during which either party may dispute the rejection.
+        /// Submissions with a review panel are decided by the panel instead.
+        #[pallet::weight(T::WeightInfo::review_submission())]
+        #[pallet::call_index(5)]
+        pub fn review_submission(
+            origin: OriginFor<T>,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+            approve: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!<ReviewPanels<T>>::contains_key(&who, &resource_location), Error::<T>::ReviewedByPanel);
+            Self::conclude_review(who, resource_location, approve)?;
+            Ok(())
+        }
+
+        /// Escalates a submission to the dispute origin. The poster or the assignee may dispute
+        /// a submission that is in review, or a rejection within its dispute window.
+        #[pallet::weight(T::WeightInfo::dispute_submission())]
+        #[pallet::call_index(6)]
+        pub fn dispute_submission(
+            origin: OriginFor<T>,
+            poster: T::AccountId,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            <SubmissionsList<T>>::try_mutate(&poster, &resource_location, |submission| -> DispatchResult {
+                let submission = submission.as_mut().ok_or(Error::<T>::SubmissionDoesNotExist)?;
+                ensure!(
+                    who == poster || submission.assignee.as_ref() == Some(&who),
+                    Error::<T>::NotParticipant
+                );
+                match (submission.status, submission.dispute_deadline) {
+                    (SubmissionStatus::InReview, _) => {},
+                    (SubmissionStatus::Rejected, Some(deadline)) => ensure!(
+                        frame_system::Pallet::<T>::block_number() <= deadline,
+                        Error::<T>::DisputeWindowClosed
+                    ),
+                    _ => return Err(Error::<T>::InvalidSubmissionStatus.into()),
+                }
+                submission.status = SubmissionStatus::Disputed;
+                submission.dispute_deadline = None;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::SubmissionDisputed { who, poster, resource_location });
+            Ok(())
+        }
+
+        /// Settles a disputed submission, either completing it and paying the bounty to the
+        /// assignee or rejecting it and refunding the bounty to the poster.
+        #[pallet::weight(T::WeightInfo::resolve_dispute())]
+        #[pallet::call_index(7)]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            poster: T::AccountId,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+            approve: bool,
+        ) -> DispatchResult {
+            T::DisputeOrigin::ensure_origin(origin)?;
+            let submission = <SubmissionsList<T>>::try_mutate(&poster, &resource_location, |submission| {
+                let submission = submission.as_mut().ok_or(Error::<T>::SubmissionDoesNotExist)?;
+                ensure!(submission.status == SubmissionStatus::Disputed, Error::<T>::InvalidSubmissionStatus);
+                let disputed = submission.clone();
+                submission.status = if approve { SubmissionStatus::Completed } else { SubmissionStatus::Rejected };
+                submission.bounty = Zero::zero();
+                Ok::<_, DispatchError>(disputed)
+            })?;
+            Self::end_assignment(&poster, &resource_location)?;
+            if approve {
+                Self::pay_bounty(&poster, &resource_location, &submission)?;
+            } else {
+                Self::refund_bounty(&poster, &resource_location, submission.bounty)?;
+            }
+            Self::deposit_event(Event::DisputeResolved { poster, resource_location, approved: approve });
+            Ok(())
+        }
+
+        /// Refunds the bounty of a rejected submission to its poster once the dispute window has
+        /// passed. Callable by anyone.
+        #[pallet::weight(T::WeightInfo::settle_submission())]
+        #[pallet::call_index(8)]
+        pub fn settle_submission(
+            origin: OriginFor<T>,
+            poster: T::AccountId,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let bounty = <SubmissionsList<T>>::try_mutate(&poster, &resource_location, |submission| {
+                let submission = submission.as_mut().ok_or(Error::<T>::SubmissionDoesNotExist)?;
+                let deadline = submission.dispute_deadline.ok_or(Error::<T>::InvalidSubmissionStatus)?;
+                ensure!(frame_system::Pallet::<T>::block_number() > deadline, Error::<T>::DisputeWindowOpen);
+                submission.dispute_deadline = None;
+                Ok::<_, DispatchError>(core::mem::take(&mut submission.bounty))
+            })?;
+            Self::refund_bounty(&poster, &resource_location, bounty)?;
+            Ok(())
+        }
+
+        /// Hands the review of a submission posted by the origin to a panel of `slots`
+        /// reviewers, `quorum` of whose matching verdicts decide it. The quorum must be a
+        /// majority of the slots. Zero slots removes the panel, leaving the review to the
+        /// poster. The panel can only be changed before the work is handed in.
+        #[pallet::weight(T::WeightInfo::configure_review())]
+        #[pallet::call_index(9)]
+        pub fn configure_review(
+            origin: OriginFor<T>,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+            slots: u32,
+            quorum: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let submission =
+                SubmissionsList::<T>::get(&who, &resource_location).ok_or(Error::<T>::SubmissionDoesNotExist)?;
             ensure!(
-                !SubmissionsList::<T>::get(&poster, &resource_location),
-                Error::<T>::SubmissionAlreadyAssigned
+                matches!(submission.status, SubmissionStatus::Open | SubmissionStatus::Assigned),
+                Error::<T>::InvalidSubmissionStatus
             );
-            T::Currency::set_lock(T::LockId::get(), &who, 10u32.into(), WithdrawReasons::all());
-            Self::deposit_event(Event::InfostratusUnlock { account: who.clone(), amount: T::Currency::free_balance(&who) });
-            <AssignmentsList<T>>::try_mutate(
-                &who,
-                resource_location.clone(),
-                |value| -> DispatchResult {
-                    *value = ASSIGNMENT_EXISTS;
-                    Ok(())
+            if slots == 0 {
+                <ReviewPanels<T>>::remove(&who, &resource_location);
+            } else {
+                ensure!(
+                    slots <= T::MaxReviewers::get() && quorum > slots / 2 && quorum <= slots,
+                    Error::<T>::InvalidReviewPanel
+                );
+                <ReviewPanels<T>>::insert(
+                    &who,
+                    &resource_location,
+                    ReviewPanel { slots, quorum, verdicts: BoundedVec::new() },
+                );
+            }
+            Self::deposit_event(Event::ReviewPanelConfigured { poster: who, resource_location, slots, quorum });
+            Ok(())
+        }
+
+        /// Gives the origin's verdict on the completed work of a submission with a review
+        /// panel, taking one of its slots. Once `quorum` verdicts agree the submission is
+        /// approved or rejected as if by its poster. If every slot is taken without a quorum
+        /// the submission is disputed.
+        #[pallet::weight(T::WeightInfo::submit_verdict())]
+        #[pallet::call_index(10)]
+        pub fn submit_verdict(
+            origin: OriginFor<T>,
+            poster: T::AccountId,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+            approve: bool,
+            comment_hash: Option<ContentHashOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            if T::Currency::total_balance(&who) < T::Currency::minimum_balance() {
+                return Err(Error::<T>::InsufficientBalance.into());
+            }
+            let submission =
+                SubmissionsList::<T>::get(&poster, &resource_location).ok_or(Error::<T>::SubmissionDoesNotExist)?;
+            ensure!(
+                who != poster && submission.assignee.as_ref() != Some(&who),
+                Error::<T>::ReviewerIsParticipant
+            );
+            ensure!(submission.status == SubmissionStatus::InReview, Error::<T>::InvalidSubmissionStatus);
+            Self::ensure_eligible(&poster, &resource_location, &who)?;
+            ensure!(
+                comment_hash.as_ref().is_none_or(|hash| is_valid_multihash(hash)),
+                Error::<T>::InvalidContentHash
+            );
+            let (approvals, rejections, slots, quorum) = <ReviewPanels<T>>::try_mutate(&poster, &resource_location, |panel| {
+                let panel = panel.as_mut().ok_or(Error::<T>::NoReviewPanel)?;
+                ensure!(
+                    !panel.verdicts.iter().any(|verdict| verdict.reviewer == who),
+                    Error::<T>::AlreadyReviewed
+                );
+                ensure!((panel.verdicts.len() as u32) < panel.slots, Error::<T>::ReviewSlotsFull);
+                panel
+                    .verdicts
+                    .try_push(Verdict { reviewer: who.clone(), approve, comment_hash })
+                    .map_err(|_| Error::<T>::ReviewSlotsFull)?;
+                let (approvals, rejections) = panel.tally();
+                Ok::<_, DispatchError>((approvals, rejections, panel.slots, panel.quorum))
+            })?;
+            Self::deposit_event(Event::VerdictSubmitted {
+                reviewer: who,
+                poster: poster.clone(),
+                resource_location: resource_location.clone(),
+                approve,
+            });
+
+            let approved = if approvals >= quorum {
+                Some(true)
+            } else if rejections >= quorum {
+                Some(false)
+            } else if approvals.saturating_add(rejections) >= slots {
+                None
+            } else {
+                return Ok(());
+            };
+            Self::deposit_event(Event::ReviewConcluded {
+                poster: poster.clone(),
+                resource_location: resource_location.clone(),
+                approvals,
+                rejections,
+                approved,
+            });
+            match approved {
+                Some(approve) => Self::conclude_review(poster, resource_location, approve)?,
+                None => <SubmissionsList<T>>::mutate(&poster, &resource_location, |submission| {
+                    if let Some(submission) = submission {
+                        submission.status = SubmissionStatus::Disputed;
+                    }
+                }),
+            }
+            Ok(())
+        }
+
+        /// Requires assignees and panel reviewers of a submission posted by the origin to be
+        /// certified by `issuer`, or lifts the requirement if `issuer` is `None`. What the
+        /// requirement means is up to the runtime's `ReviewerEligibility`. Accounts already
+        /// assigned to or reviewing the submission are not affected.
+        #[pallet::weight(T::WeightInfo::set_reviewer_issuer())]
+        #[pallet::call_index(11)]
+        pub fn set_reviewer_issuer(
+            origin: OriginFor<T>,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+            issuer: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let submission =
+                SubmissionsList::<T>::get(&who, &resource_location).ok_or(Error::<T>::SubmissionDoesNotExist)?;
+            ensure!(!submission.status.is_final(), Error::<T>::InvalidSubmissionStatus);
+            match &issuer {
+                Some(issuer) => <ReviewerIssuers<T>>::insert(&who, &resource_location, issuer),
+                None => <ReviewerIssuers<T>>::remove(&who, &resource_location),
+            }
+            Self::deposit_event(Event::ReviewerIssuerSet { poster: who, resource_location, issuer });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Returns up to `limit` submissions waiting for an assignee in index order, continuing
+        /// after the submission posted by `start_after.0` at `start_after.1` if given.
+        pub fn open_submissions(
+            start_after: Option<(T::AccountId, ResourceLocationOf<T>)>,
+            limit: u32,
+        ) -> Vec<(T::AccountId, ResourceLocationOf<T>, SubmissionInfoOf<T>)> {
+            let keys = match start_after {
+                Some((poster, resource_location)) => <OpenSubmissions<T>>::iter_keys_from(
+                    <OpenSubmissions<T>>::hashed_key_for(poster, resource_location),
+                ),
+                None => <OpenSubmissions<T>>::iter_keys(),
+            };
+            keys.filter_map(|(poster, resource_location)| {
+                let submission = <SubmissionsList<T>>::get(&poster, &resource_location)?;
+                Some((poster, resource_location, submission))
+            })
+            .take(limit as usize)
+            .collect()
+        }
+
+        /// Returns up to `limit` submissions `assignee` is assigned to as pairs of resource
+        /// location and poster, continuing after the resource location `start_after` if given.
+        pub fn assignments_of(
+            assignee: &T::AccountId,
+            start_after: Option<ResourceLocationOf<T>>,
+            limit: u32,
+        ) -> Vec<(ResourceLocationOf<T>, T::AccountId)> {
+            let assignments = match start_after {
+                Some(resource_location) => <AssignmentsList<T>>::iter_prefix_from(
+                    assignee,
+                    <AssignmentsList<T>>::hashed_key_for(assignee, resource_location),
+                ),
+                None => <AssignmentsList<T>>::iter_prefix(assignee),
+            };
+            assignments.take(limit as usize).collect()
+        }
+
+        /// Schedules the submission of `poster` at `resource_location` to be checked for expiry at
+        /// `block`.
+        pub(crate) fn schedule_expiry(
+            block: BlockNumberFor<T>,
+            poster: &T::AccountId,
+            resource_location: &BoundedVec<u8, T::MaxSize>,
+        ) {
+            if !<ExpiryCursor<T>>::exists() {
+                <ExpiryCursor<T>>::put(frame_system::Pallet::<T>::block_number());
+            }
+            <ExpiryQueue<T>>::insert(block, (poster, resource_location), ());
+        }
+
+        /// Lists a submission whose assignment ended as open again. One whose time-to-live has
+        /// already passed expires in the current block's sweep.
+        fn reopen(
+            poster: &T::AccountId,
+            resource_location: &BoundedVec<u8, T::MaxSize>,
+            expires_at: BlockNumberFor<T>,
+        ) {
+            <OpenSubmissions<T>>::insert(poster, resource_location, ());
+            let now = frame_system::Pallet::<T>::block_number();
+            if expires_at < now {
+                <SubmissionsList<T>>::mutate(poster, resource_location, |submission| {
+                    if let Some(submission) = submission {
+                        submission.expires_at = now;
+                    }
+                });
+            }
+            Self::schedule_expiry(expires_at.max(now), poster, resource_location);
+        }
+
+        /// Expires the submissions and assignments scheduled up to `now`, spending at most
+        /// `limit`. Whatever does not fit is left for the next call.
+        pub(crate) fn sweep_expiries(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(limit);
+            if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+                return meter.consumed();
+            }
+            let Some(mut block) = <ExpiryCursor<T>>::get() else {
+                return meter.consumed();
+            };
+            let expiry_weight = T::WeightInfo::expire_submission().max(T::WeightInfo::expire_assignment());
+            while block <= now {
+                if meter.try_consume(T::DbWeight::get().reads(1)).is_err() {
+                    break;
+                }
+                let Some(key) = <ExpiryQueue<T>>::iter_key_prefix(block).next() else {
+                    block.saturating_inc();
+                    continue;
+                };
+                if meter.try_consume(expiry_weight).is_err() {
+                    break;
+                }
+                <ExpiryQueue<T>>::remove(block, &key);
+                let (poster, resource_location) = key;
+                let _ = with_storage_layer(|| Self::expire(block, &poster, &resource_location)).defensive();
+            }
+            <ExpiryCursor<T>>::put(block);
+            meter.consumed()
+        }
+
+        /// Expires the submission of `poster` at `resource_location` if it is still open at
+        /// `block`, or lapses its assignment if that is still due at `block`.
+        pub(crate) fn expire(
+            block: BlockNumberFor<T>,
+            poster: &T::AccountId,
+            resource_location: &BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResult {
+            let Some(submission) = <SubmissionsList<T>>::get(poster, resource_location) else {
+                return Ok(());
+            };
+            match (submission.status, submission.assignee) {
+                (SubmissionStatus::Open, _) if submission.expires_at == block => {
+                    <SubmissionsList<T>>::mutate(poster, resource_location, |submission| {
+                        if let Some(submission) = submission {
+                            submission.status = SubmissionStatus::Expired;
+                            submission.deposit = Zero::zero();
+                            submission.bounty = Zero::zero();
+                        }
+                    });
+                    <OpenSubmissions<T>>::remove(poster, resource_location);
+                    Self::refund_bounty(poster, resource_location, submission.bounty)?;
+                    Self::release_deposit(poster, submission.deposit)?;
+                    Self::deposit_event(Event::SubmissionExpired {
+                        poster: poster.clone(),
+                        resource_location: resource_location.clone(),
+                    });
                 },
-            )?;
-            <SubmissionsList<T>>::try_mutate(
-                &poster,
-                resource_location.clone(),
-                |value| -> DispatchResult {
-                    *value = ASSIGNMENT_EXISTS;
-                    Ok(())
+                (SubmissionStatus::Assigned, Some(assignee)) if submission.assignment_deadline == Some(block) => {
+                    <SubmissionsList<T>>::mutate(poster, resource_location, |submission| {
+                        if let Some(submission) = submission {
+                            submission.status = SubmissionStatus::Open;
+                            submission.assignee = None;
+                            submission.assignee_deposit = Zero::zero();
+                            submission.assignment_deadline = None;
+                        }
+                    });
+                    <AssignmentsList<T>>::remove(&assignee, resource_location);
+                    Self::reopen(poster, resource_location, submission.expires_at);
+                    Self::release_deposit(&assignee, submission.assignee_deposit)?;
+                    Self::deposit_event(Event::AssignmentExpired {
+                        poster: poster.clone(),
+                        resource_location: resource_location.clone(),
+                        who: assignee,
+                    });
                 },
+                _ => {},
+            }
+            Ok(())
+        }
+
+        /// Ensures `reviewer` may work on or review the submission of `poster` at
+        /// `resource_location`.
+        fn ensure_eligible(
+            poster: &T::AccountId,
+            resource_location: &BoundedVec<u8, T::MaxSize>,
+            reviewer: &T::AccountId,
+        ) -> DispatchResult {
+            let issuer = <ReviewerIssuers<T>>::get(poster, resource_location);
+            ensure!(
+                T::ReviewerEligibility::is_eligible(poster, reviewer, issuer.as_ref()),
+                Error::<T>::NotEligible
+            );
+            Ok(())
+        }
+
+        /// Approves or rejects the completed work on a submission, ending it. See
+        /// `review_submission`.
+        fn conclude_review(
+            poster: T::AccountId,
+            resource_location: BoundedVec<u8, T::MaxSize>,
+            approve: bool,
+        ) -> DispatchResult {
+            let submission = <SubmissionsList<T>>::try_mutate(&poster, &resource_location, |submission| {
+                let submission = submission.as_mut().ok_or(Error::<T>::SubmissionDoesNotExist)?;
+                ensure!(submission.status == SubmissionStatus::InReview, Error::<T>::InvalidSubmissionStatus);
+                let reviewed = submission.clone();
+                if approve {
+                    submission.status = SubmissionStatus::Completed;
+                    submission.bounty = Zero::zero();
+                } else {
+                    submission.status = SubmissionStatus::Rejected;
+                    if !submission.bounty.is_zero() {
+                        let now = frame_system::Pallet::<T>::block_number();
+                        submission.dispute_deadline = Some(now.saturating_add(T::DisputePeriod::get()));
+                    }
+                }
+                Ok::<_, DispatchError>(reviewed)
+            })?;
+            Self::end_assignment(&poster, &resource_location)?;
+            if approve {
+                Self::pay_bounty(&poster, &resource_location, &submission)?;
+                Self::deposit_event(Event::SubmissionApproved { poster, resource_location });
+            } else {
+                Self::deposit_event(Event::SubmissionRejected { poster, resource_location });
+            }
+            Ok(())
+        }
+
+        /// Stores the metadata of a submission and indexes it under its tags, replacing the
+        /// metadata of any earlier submission of the same resource location.
+        fn record_metadata(
+            poster: &T::AccountId,
+            resource_location: &BoundedVec<u8, T::MaxSize>,
+            metadata: SubmissionMetadataOf<T>,
+        ) {
+            let key = (poster.clone(), resource_location.clone());
+            if let Some(previous) = <SubmissionMetadataList<T>>::get(poster, resource_location) {
+                for tag in previous.tags.iter() {
+                    <SubmissionsByTag<T>>::remove(tag, &key);
+                }
+            }
+            for tag in metadata.tags.iter() {
+                <SubmissionsByTag<T>>::insert(tag, &key, ());
+            }
+            <SubmissionMetadataList<T>>::insert(poster, resource_location, metadata);
+        }
+
+        /// Removes the assignment of a submission that has ended and releases the deposits of its
+        /// poster and assignee that are still held.
+        fn end_assignment(poster: &T::AccountId, resource_location: &BoundedVec<u8, T::MaxSize>) -> DispatchResult {
+            let (assignee, deposit, assignee_deposit) =
+                <SubmissionsList<T>>::try_mutate(poster, resource_location, |submission| {
+                    let submission = submission.as_mut().ok_or(Error::<T>::SubmissionDoesNotExist)?;
+                    Ok::<_, DispatchError>((
+                        submission.assignee.clone(),
+                        core::mem::take(&mut submission.deposit),
+                        core::mem::take(&mut submission.assignee_deposit),
+                    ))
+                })?;
+            if let Some(assignee) = assignee {
+                if <AssignmentsList<T>>::get(&assignee, resource_location).as_ref() == Some(poster) {
+                    <AssignmentsList<T>>::remove(&assignee, resource_location);
+                }
+                Self::release_deposit(&assignee, assignee_deposit)?;
+            }
+            Self::release_deposit(poster, deposit)
+        }
+
+        /// Transfers the bounty held from `poster` to the assignee of the submission.
+        fn pay_bounty(
+            poster: &T::AccountId,
+            resource_location: &BoundedVec<u8, T::MaxSize>,
+            submission: &SubmissionInfoOf<T>,
+        ) -> DispatchResult {
+            let Some(assignee) = submission.assignee.clone() else {
+                return Self::refund_bounty(poster, resource_location, submission.bounty);
+            };
+            if submission.bounty.is_zero() {
+                return Ok(());
+            }
+            let amount = T::Currency::transfer_on_hold(
+                &HoldReason::SubmissionBounty.into(),
+                poster,
+                &assignee,
+                submission.bounty,
+                Precision::BestEffort,
+                Restriction::Free,
+                Fortitude::Polite,
             )?;
-            Self::deposit_event(Event::SubmissionAssigned { resource_location, who });
-            Ok(().into())
+            Self::deposit_event(Event::BountyPaid {
+                assignee,
+                resource_location: resource_location.clone(),
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Releases a bounty held from `poster` back to them.
+        fn refund_bounty(
+            poster: &T::AccountId,
+            resource_location: &BoundedVec<u8, T::MaxSize>,
+            bounty: BalanceOf<T>,
+        ) -> DispatchResult {
+            if bounty.is_zero() {
+                return Ok(());
+            }
+            let amount =
+                T::Currency::release(&HoldReason::SubmissionBounty.into(), poster, bounty, Precision::BestEffort)?;
+            Self::deposit_event(Event::BountyRefunded {
+                poster: poster.clone(),
+                resource_location: resource_location.clone(),
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Holds `LockPrice` from `who` as the deposit for a submission or assignment.
+        fn hold_deposit(who: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+            let deposit: BalanceOf<T> = T::LockPrice::get().into();
+            T::Currency::hold(&HoldReason::SubmissionDeposit.into(), who, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            Self::deposit_event(Event::InfostratusLock { account: who.clone(), amount: deposit });
+            Ok(deposit)
+        }
+
+        /// Releases a submission or assignment deposit previously held from `who`.
+        fn release_deposit(who: &T::AccountId, deposit: BalanceOf<T>) -> DispatchResult {
+            if deposit.is_zero() {
+                return Ok(());
+            }
+            let amount =
+                T::Currency::release(&HoldReason::SubmissionDeposit.into(), who, deposit, Precision::BestEffort)?;
+            Self::deposit_event(Event::InfostratusUnlock { account: who.clone(), amount });
+            Ok(())
         }
     }
 }
@@ -0,0 +1,137 @@
+//! Storage migrations for the infostratus pallet.
+
+/// Migrates submissions stored as a bare `bool` to `SubmissionInfo`, assignments stored as a bare
+/// `bool` to the poster of the submission they are for, and their deposits from the lock shared
+/// by all entries of an account to a hold per submission and assignment.
+pub mod v1 {
+    use alloc::{
+        collections::{BTreeMap, BTreeSet},
+        vec::Vec,
+    };
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        sp_runtime::traits::{Saturating, Zero},
+        traits::{fungible::MutateHold, LockIdentifier, LockableCurrency, UncheckedOnRuntimeUpgrade},
+        BoundedVec,
+    };
+
+    use crate::{
+        pallet::BalanceOf, AssignmentsList, Config, HoldReason, OpenSubmissions, Pallet, SubmissionInfo,
+        SubmissionStatus, SubmissionsList,
+    };
+
+    mod v0 {
+        use super::*;
+
+        /// Maps a poster and a resource location to whether the submission has been assigned.
+        #[frame_support::storage_alias]
+        pub type SubmissionsList<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            Blake2_128Concat,
+            BoundedVec<u8, <T as Config>::MaxSize>,
+            bool,
+            OptionQuery,
+        >;
+    }
+
+    /// Removes the `LockId` lock of every poster and assignee. Then turns every assignment
+    /// stored as `true` into the poster of an assigned submission at the same resource location,
+    /// and every submission stored as a `bool` into a `SubmissionInfo` created in the block of
+    /// the upgrade, which is the earliest block it is known to exist in, with no bounty. A
+    /// submission stored as `true` whose assignee is found becomes `Assigned` with a fresh
+    /// `AssignmentPeriod`; every other submission becomes `Open` with a fresh `SubmissionTtl`
+    /// and is indexed in `OpenSubmissions`. `LockPrice` is held from the poster of every
+    /// submission and from the assignee of every assignment kept; a deposit that cannot be held
+    /// is recorded as zero. Assignments that match no assigned submission are removed.
+    pub struct UncheckedMigrateToV1<T, OldCurrency, LockId>(PhantomData<(T, OldCurrency, LockId)>);
+
+    impl<T, OldCurrency, LockId> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T, OldCurrency, LockId>
+    where
+        T: Config,
+        OldCurrency: LockableCurrency<T::AccountId>,
+        LockId: Get<LockIdentifier>,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            // Posters of the assigned submissions at each resource location
+            let mut assigned = BTreeMap::<_, Vec<_>>::new();
+            let mut accounts = BTreeSet::new();
+            let mut submissions = 0u64;
+            for (poster, resource_location, is_assigned) in v0::SubmissionsList::<T>::iter() {
+                submissions += 1;
+                if is_assigned {
+                    assigned.entry(resource_location).or_default().push(poster.clone());
+                }
+                accounts.insert(poster);
+            }
+            accounts.extend(<AssignmentsList<T>>::iter_keys().map(|(assignee, _)| assignee));
+            for account in accounts.iter() {
+                OldCurrency::remove_lock(LockId::get(), account);
+            }
+            let hold_deposit = |who: &T::AccountId| {
+                let deposit: BalanceOf<T> = T::LockPrice::get().into();
+                match T::Currency::hold(&HoldReason::SubmissionDeposit.into(), who, deposit) {
+                    Ok(()) => deposit,
+                    Err(_) => Zero::zero(),
+                }
+            };
+            let mut assignees = BTreeMap::new();
+            let mut assignments = 0u64;
+            <AssignmentsList<T>>::translate::<bool, _>(|assignee, resource_location, active| {
+                assignments += 1;
+                if !active {
+                    return None;
+                }
+                let poster = assigned.get_mut(&resource_location)?.pop()?;
+                assignees.insert((poster.clone(), resource_location), assignee);
+                Some(poster)
+            });
+            let now = frame_system::Pallet::<T>::block_number();
+            let expires_at = now.saturating_add(T::SubmissionTtl::get());
+            let assignment_deadline = now.saturating_add(T::AssignmentPeriod::get());
+            <SubmissionsList<T>>::translate::<bool, _>(|poster, resource_location, _| {
+                let assignee = assignees.remove(&(poster.clone(), resource_location.clone()));
+                let (status, deadline) = match assignee {
+                    Some(_) => (SubmissionStatus::Assigned, assignment_deadline),
+                    None => {
+                        <OpenSubmissions<T>>::insert(&poster, &resource_location, ());
+                        (SubmissionStatus::Open, expires_at)
+                    },
+                };
+                Pallet::<T>::schedule_expiry(deadline, &poster, &resource_location);
+                Some(SubmissionInfo {
+                    status,
+                    assignment_deadline: assignee.is_some().then_some(assignment_deadline),
+                    deposit: hold_deposit(&poster),
+                    assignee_deposit: assignee.as_ref().map(hold_deposit).unwrap_or_else(Zero::zero),
+                    assignee,
+                    bounty: Zero::zero(),
+                    dispute_deadline: None,
+                    created_at: now,
+                    expires_at,
+                })
+            });
+            let accounts = accounts.len() as u64;
+            let deposits = submissions + assignments;
+            // Each submission is read twice, translated, scheduled to expire and, unless
+            // assigned, indexed as open; each assignment is read twice and translated; each
+            // deposit is held; each account has its lock removed.
+            T::DbWeight::get().reads_writes(
+                submissions * 2 + assignments * 2 + deposits * 2 + accounts * 2 + 1,
+                submissions * 3 + assignments + deposits * 2 + accounts * 2 + 1,
+            )
+        }
+    }
+
+    /// Runs `UncheckedMigrateToV1` if the pallet's storage version is 0, then sets it to 1.
+    pub type MigrateToV1<T, OldCurrency, LockId> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateToV1<T, OldCurrency, LockId>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
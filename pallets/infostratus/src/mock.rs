@@ -39,7 +39,10 @@ impl frame_system::Config for Test {
 
 parameter_types! {
     pub const ExistentialDeposit: u128 = 1;
-    pub const MockLockIdentifier: [u8; 8] = *b"infolock";
+    pub const DisputePeriod: u64 = 5;
+    pub const SubmissionTtl: u64 = 100;
+    pub const AssignmentPeriod: u64 = 20;
+    pub const InfostratusLockId: [u8; 8] = *b"infosloc";
 }
 
 impl pallet_balances::Config for Test {
@@ -52,7 +55,7 @@ impl pallet_balances::Config for Test {
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type FreezeIdentifier = [u8; 8];
     type MaxFreezes = ();
     type RuntimeFreezeReason = ();
@@ -63,9 +66,54 @@ impl pallet_infostratus::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type MaxSize = ConstU32<1024>;
-    type LockId = MockLockIdentifier;
     type LockPrice = ConstU32<10>;
+    type DisputePeriod = DisputePeriod;
+    type DisputeOrigin = frame_system::EnsureRoot<u64>;
+    type SubmissionTtl = SubmissionTtl;
+    type AssignmentPeriod = AssignmentPeriod;
+    type MaxTitleLength = ConstU32<64>;
+    type MaxContentHashLength = ConstU32<68>;
+    type MaxMimeTypeLength = ConstU32<64>;
+    type MaxTagLength = ConstU32<32>;
+    type MaxTags = ConstU32<4>;
+    type MaxReviewers = ConstU32<3>;
+    type ReviewerEligibility = MockEligibility;
+}
+
+thread_local! {
+    /// The `(issuer, reviewer)` pairs for which the issuer has certified the reviewer.
+    pub static CERTIFIED: core::cell::RefCell<Vec<(u64, u64)>> = const { core::cell::RefCell::new(Vec::new()) };
+    /// The `(poster, reviewer)` pairs for which the poster trusts the reviewer.
+    pub static TRUSTED: core::cell::RefCell<Vec<(u64, u64)>> = const { core::cell::RefCell::new(Vec::new()) };
+}
+
+/// Allows everyone unless the poster chose an issuer, in which case the reviewer needs to be
+/// certified by that issuer or trusted by the poster.
+pub struct MockEligibility;
+
+impl pallet_infostratus::ReviewerEligibility<u64> for MockEligibility {
+    fn is_eligible(poster: &u64, reviewer: &u64, issuer: Option<&u64>) -> bool {
+        let Some(issuer) = issuer else {
+            return true;
+        };
+        CERTIFIED.with(|certified| certified.borrow().contains(&(*issuer, *reviewer))) ||
+            TRUSTED.with(|trusted| trusted.borrow().contains(&(*poster, *reviewer)))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn make_eligible(poster: &u64, reviewer: &u64) {
+        trust(*poster, *reviewer);
+    }
+}
+
+pub fn certify(issuer: u64, reviewer: u64) {
+    CERTIFIED.with(|certified| certified.borrow_mut().push((issuer, reviewer)));
+}
+
+pub fn trust(poster: u64, reviewer: u64) {
+    TRUSTED.with(|trusted| trusted.borrow_mut().push((poster, reviewer)));
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
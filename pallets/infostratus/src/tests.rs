@@ -1,5 +1,5 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use crate::{mock::*, pallet::{SubmissionMetadataOf, TagOf}, Error, ExpiryCursor, HoldReason, SubmissionInfo, SubmissionMetadata, OpenSubmissions, SubmissionStatus, SubmissionsByTag};
+use frame_support::{assert_noop, assert_ok, traits::{fungible::InspectHold, Currency, Get, Hooks}, weights::Weight};
 use sp_core::ConstU32;
 use sp_runtime::BoundedVec;
 
@@ -9,7 +9,7 @@ fn create_submission_entry_works_and_emits_event() {
         System::set_block_number(1);
         let _ = Balances::deposit_creating(&1, 100);
         let resource = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TEST".to_vec()).unwrap();
-        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone()));
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone(), metadata(), None));
         System::assert_last_event(
             crate::Event::SubmissionSent { who: 1, resource_location: resource }.into()
         );
@@ -22,9 +22,9 @@ fn cannot_create_duplicate_submission() {
         System::set_block_number(1);
         let _ = Balances::deposit_creating(&1, 100);
         let resource = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TEST".to_vec()).unwrap();
-        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone()));
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone(), metadata(), None));
         assert_noop!(
-            Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone()),
+            Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone(), metadata(), None),
             Error::<Test>::SubmissionExists
         );
     });
@@ -37,7 +37,7 @@ fn request_submission_assignment_works_and_emits_event() {
         let _ = Balances::deposit_creating(&1, 100);
         let _ = Balances::deposit_creating(&2, 100);
         let resource = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TEST".to_vec()).unwrap();
-        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone()));
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone(), metadata(), None));
         assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource.clone()));
         System::assert_last_event(
             crate::Event::SubmissionAssigned { resource_location: resource, who: 2 }.into()
@@ -67,7 +67,7 @@ fn cannot_assign_already_assigned_submission() {
         let _ = Balances::deposit_creating(&2, 100);
         let _ = Balances::deposit_creating(&3, 100);
         let resource = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TEST".to_vec()).unwrap();
-        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone()));
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone(), metadata(), None));
         assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource.clone()));
         assert_noop!(
             Infostratus::request_submission_assignment(RuntimeOrigin::signed(3), 1, resource.clone()),
@@ -82,10 +82,924 @@ fn cannot_assign_own_submission() {
         System::set_block_number(1);
         let _ = Balances::deposit_creating(&1, 100);
         let resource = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TEST".to_vec()).unwrap();
-        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone()));
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource.clone(), metadata(), None));
         assert_noop!(
             Infostratus::request_submission_assignment(RuntimeOrigin::signed(1), 1, resource),
             Error::<Test>::CannotAssignOwnSubmission
         );
     });
 }
+
+fn resource() -> BoundedVec<u8, ConstU32<1024>> {
+    BoundedVec::try_from(b"TEST".to_vec()).unwrap()
+}
+
+fn metadata() -> SubmissionMetadataOf<Test> {
+    SubmissionMetadata {
+        title: BoundedVec::try_from(b"Test submission".to_vec()).unwrap(),
+        // A sha2-256 multihash of a 32-byte digest.
+        content_hash: BoundedVec::try_from([&[0x12, 0x20][..], &[0xab; 32][..]].concat()).unwrap(),
+        mime_type: BoundedVec::try_from(b"text/html".to_vec()).unwrap(),
+        tags: BoundedVec::try_from(vec![tag(b"news")]).unwrap(),
+    }
+}
+
+fn tag(name: &[u8]) -> TagOf<Test> {
+    BoundedVec::try_from(name.to_vec()).unwrap()
+}
+
+fn held_deposit(who: u64) -> u128 {
+    Balances::balance_on_hold(&HoldReason::SubmissionDeposit.into(), &who)
+}
+
+fn assigned_submission() {
+    let _ = Balances::deposit_creating(&1, 100);
+    let _ = Balances::deposit_creating(&2, 100);
+    assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+    assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()));
+}
+
+#[test]
+fn submission_starts_open_and_becomes_assigned() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        assert_eq!(
+            Infostratus::submissions_list(1, resource()),
+            Some(SubmissionInfo {
+                status: SubmissionStatus::Open,
+                assignee: None,
+                deposit: 10,
+                assignee_deposit: 0,
+                bounty: 0,
+                dispute_deadline: None,
+                created_at: 1,
+                expires_at: 101,
+                assignment_deadline: None,
+            })
+        );
+        let _ = Balances::deposit_creating(&2, 100);
+        assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()));
+        assert_eq!(
+            Infostratus::submissions_list(1, resource()),
+            Some(SubmissionInfo {
+                status: SubmissionStatus::Assigned,
+                assignee: Some(2),
+                deposit: 10,
+                assignee_deposit: 10,
+                bounty: 0,
+                dispute_deadline: None,
+                created_at: 1,
+                expires_at: 101,
+                assignment_deadline: Some(21),
+            })
+        );
+        assert_eq!(Infostratus::assignments_list(2, resource()), Some(1));
+    });
+}
+
+#[test]
+fn poster_can_withdraw_open_submission() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        assert_eq!(held_deposit(1), 10);
+        assert_ok!(Infostratus::withdraw_submission(RuntimeOrigin::signed(1), resource()));
+        System::assert_last_event(
+            crate::Event::SubmissionWithdrawn { who: 1, resource_location: resource() }.into()
+        );
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Withdrawn);
+        assert_eq!(held_deposit(1), 0);
+        let _ = Balances::deposit_creating(&2, 100);
+        assert_noop!(
+            Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()),
+            Error::<Test>::InvalidSubmissionStatus
+        );
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+    });
+}
+
+#[test]
+fn cannot_withdraw_assigned_submission() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assigned_submission();
+        assert_noop!(
+            Infostratus::withdraw_submission(RuntimeOrigin::signed(1), resource()),
+            Error::<Test>::InvalidSubmissionStatus
+        );
+    });
+}
+
+#[test]
+fn assignee_can_release_assignment() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assigned_submission();
+        assert_ok!(Infostratus::release_assignment(RuntimeOrigin::signed(2), resource()));
+        System::assert_last_event(
+            crate::Event::AssignmentReleased { resource_location: resource(), who: 2 }.into()
+        );
+        assert_eq!(
+            Infostratus::submissions_list(1, resource()),
+            Some(SubmissionInfo {
+                status: SubmissionStatus::Open,
+                assignee: None,
+                deposit: 10,
+                assignee_deposit: 0,
+                bounty: 0,
+                dispute_deadline: None,
+                created_at: 1,
+                expires_at: 101,
+                assignment_deadline: None,
+            })
+        );
+        assert_eq!(Infostratus::assignments_list(2, resource()), None);
+        assert_eq!(held_deposit(2), 0);
+        assert_eq!(held_deposit(1), 10);
+
+        let _ = Balances::deposit_creating(&3, 100);
+        assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(3), 1, resource()));
+    });
+}
+
+#[test]
+fn approved_submission_completes_and_releases_locks() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assigned_submission();
+        assert_noop!(
+            Infostratus::review_submission(RuntimeOrigin::signed(1), resource(), true),
+            Error::<Test>::InvalidSubmissionStatus
+        );
+        assert_noop!(
+            Infostratus::complete_assignment(RuntimeOrigin::signed(3), resource()),
+            Error::<Test>::NotAssignee
+        );
+        assert_ok!(Infostratus::complete_assignment(RuntimeOrigin::signed(2), resource()));
+        System::assert_last_event(
+            crate::Event::AssignmentCompleted { resource_location: resource(), who: 2 }.into()
+        );
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::InReview);
+        assert_noop!(
+            Infostratus::release_assignment(RuntimeOrigin::signed(2), resource()),
+            Error::<Test>::InvalidSubmissionStatus
+        );
+
+        assert_ok!(Infostratus::review_submission(RuntimeOrigin::signed(1), resource(), true));
+        System::assert_last_event(
+            crate::Event::SubmissionApproved { poster: 1, resource_location: resource() }.into()
+        );
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Completed);
+        assert_eq!(Infostratus::assignments_list(2, resource()), None);
+        assert_eq!(held_deposit(1), 0);
+        assert_eq!(held_deposit(2), 0);
+    });
+}
+
+#[test]
+fn rejected_submission_is_final() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assigned_submission();
+        assert_ok!(Infostratus::complete_assignment(RuntimeOrigin::signed(2), resource()));
+        assert_ok!(Infostratus::review_submission(RuntimeOrigin::signed(1), resource(), false));
+        System::assert_last_event(
+            crate::Event::SubmissionRejected { poster: 1, resource_location: resource() }.into()
+        );
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Rejected);
+        assert_noop!(
+            Infostratus::review_submission(RuntimeOrigin::signed(1), resource(), true),
+            Error::<Test>::InvalidSubmissionStatus
+        );
+    });
+}
+
+#[test]
+fn lock_is_kept_while_other_entries_are_outstanding() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        let other = BoundedVec::<u8, ConstU32<1024>>::try_from(b"OTHER".to_vec()).unwrap();
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), other.clone(), metadata(), None));
+        assert_ok!(Infostratus::withdraw_submission(RuntimeOrigin::signed(1), resource()));
+        assert_eq!(held_deposit(1), 10);
+        assert_ok!(Infostratus::withdraw_submission(RuntimeOrigin::signed(1), other));
+        assert_eq!(held_deposit(1), 0);
+    });
+}
+
+fn bounty_submission(bounty: u128) {
+    let _ = Balances::deposit_creating(&1, 100);
+    let _ = Balances::deposit_creating(&2, 100);
+    assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), Some(bounty)));
+    assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()));
+    assert_ok!(Infostratus::complete_assignment(RuntimeOrigin::signed(2), resource()));
+}
+
+fn held_bounty(who: u64) -> u128 {
+    Balances::balance_on_hold(&HoldReason::SubmissionBounty.into(), &who)
+}
+
+#[test]
+fn bounty_is_escrowed_and_refunded_on_withdrawal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        assert_noop!(
+            Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), Some(200)),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), Some(40)));
+        System::assert_has_event(
+            crate::Event::BountyEscrowed { poster: 1, resource_location: resource(), amount: 40 }.into()
+        );
+        assert_eq!(held_bounty(1), 40);
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().bounty, 40);
+
+        assert_ok!(Infostratus::withdraw_submission(RuntimeOrigin::signed(1), resource()));
+        System::assert_has_event(
+            crate::Event::BountyRefunded { poster: 1, resource_location: resource(), amount: 40 }.into()
+        );
+        assert_eq!(held_bounty(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+    });
+}
+
+#[test]
+fn approval_pays_bounty_to_assignee() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        bounty_submission(40);
+        assert_ok!(Infostratus::review_submission(RuntimeOrigin::signed(1), resource(), true));
+        System::assert_has_event(
+            crate::Event::BountyPaid { assignee: 2, resource_location: resource(), amount: 40 }.into()
+        );
+        assert_eq!(held_bounty(1), 0);
+        assert_eq!(Balances::free_balance(1), 60);
+        assert_eq!(Balances::free_balance(2), 140);
+        assert!(Infostratus::submissions_list(1, resource()).unwrap().is_closed());
+    });
+}
+
+#[test]
+fn rejected_bounty_is_refunded_after_dispute_window() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        bounty_submission(40);
+        assert_ok!(Infostratus::review_submission(RuntimeOrigin::signed(1), resource(), false));
+        let submission = Infostratus::submissions_list(1, resource()).unwrap();
+        assert_eq!(submission.dispute_deadline, Some(6));
+        assert!(!submission.is_closed());
+        assert_eq!(held_bounty(1), 40);
+        assert_noop!(
+            Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None),
+            Error::<Test>::SubmissionExists
+        );
+        assert_noop!(
+            Infostratus::settle_submission(RuntimeOrigin::signed(3), 1, resource()),
+            Error::<Test>::DisputeWindowOpen
+        );
+
+        System::set_block_number(7);
+        assert_noop!(
+            Infostratus::dispute_submission(RuntimeOrigin::signed(2), 1, resource()),
+            Error::<Test>::DisputeWindowClosed
+        );
+        assert_ok!(Infostratus::settle_submission(RuntimeOrigin::signed(3), 1, resource()));
+        System::assert_last_event(
+            crate::Event::BountyRefunded { poster: 1, resource_location: resource(), amount: 40 }.into()
+        );
+        assert_eq!(Balances::free_balance(1), 100);
+        assert!(Infostratus::submissions_list(1, resource()).unwrap().is_closed());
+    });
+}
+
+#[test]
+fn disputed_rejection_is_resolved_by_dispute_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        bounty_submission(40);
+        assert_ok!(Infostratus::review_submission(RuntimeOrigin::signed(1), resource(), false));
+        assert_noop!(
+            Infostratus::dispute_submission(RuntimeOrigin::signed(3), 1, resource()),
+            Error::<Test>::NotParticipant
+        );
+        assert_ok!(Infostratus::dispute_submission(RuntimeOrigin::signed(2), 1, resource()));
+        System::assert_last_event(
+            crate::Event::SubmissionDisputed { who: 2, poster: 1, resource_location: resource() }.into()
+        );
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Disputed);
+
+        assert_noop!(
+            Infostratus::resolve_dispute(RuntimeOrigin::signed(1), 1, resource(), false),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Infostratus::resolve_dispute(RuntimeOrigin::root(), 1, resource(), true));
+        System::assert_has_event(
+            crate::Event::BountyPaid { assignee: 2, resource_location: resource(), amount: 40 }.into()
+        );
+        System::assert_last_event(
+            crate::Event::DisputeResolved { poster: 1, resource_location: resource(), approved: true }.into()
+        );
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Completed);
+        assert_eq!(Balances::free_balance(2), 140);
+    });
+}
+
+#[test]
+fn submission_in_review_can_be_disputed_by_poster() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        bounty_submission(40);
+        assert_ok!(Infostratus::dispute_submission(RuntimeOrigin::signed(1), 1, resource()));
+        assert_eq!(held_deposit(1), 10);
+        assert_eq!(held_deposit(2), 10);
+        assert_ok!(Infostratus::resolve_dispute(RuntimeOrigin::root(), 1, resource(), false));
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Rejected);
+        assert_eq!(Infostratus::assignments_list(2, resource()), None);
+        assert_eq!(held_deposit(1), 0);
+        assert_eq!(held_deposit(2), 0);
+        assert_eq!(held_bounty(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+    });
+}
+
+#[test]
+fn deposits_are_held_per_submission_and_summed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let other = BoundedVec::try_from(b"OTHER".to_vec()).unwrap();
+        let _ = Balances::deposit_creating(&1, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), other.clone(), metadata(), None));
+        assert_eq!(held_deposit(1), 20);
+        assert_ok!(Infostratus::withdraw_submission(RuntimeOrigin::signed(1), other));
+        System::assert_has_event(crate::Event::InfostratusUnlock { account: 1, amount: 10 }.into());
+        assert_eq!(held_deposit(1), 10);
+        assert_ok!(Infostratus::withdraw_submission(RuntimeOrigin::signed(1), resource()));
+        assert_eq!(held_deposit(1), 0);
+    });
+}
+
+#[test]
+fn requesting_assignment_holds_deposit_and_emits_lock() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assigned_submission();
+        System::assert_has_event(crate::Event::InfostratusLock { account: 2, amount: 10 }.into());
+        assert_eq!(held_deposit(2), 10);
+        assert_eq!(Balances::free_balance(2), 90);
+    });
+}
+
+#[test]
+fn cannot_create_submission_without_funds_for_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 5);
+        assert_noop!(
+            Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None),
+            Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn submission_records_metadata_and_creation_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(7);
+        let _ = Balances::deposit_creating(&1, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        assert_eq!(Infostratus::submission_metadata(1, resource()), Some(metadata()));
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().created_at, 7);
+        assert_eq!(SubmissionsByTag::<Test>::get(tag(b"news"), (1, resource())), Some(()));
+    });
+}
+
+#[test]
+fn cannot_create_submission_with_malformed_content_hash() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        for content_hash in [vec![], vec![0x12], vec![0x12, 0x20, 0xab], vec![0x12, 0x00], vec![0x12, 0x01, 0xab, 0xcd]] {
+            let mut metadata = metadata();
+            metadata.content_hash = BoundedVec::try_from(content_hash).unwrap();
+            assert_noop!(
+                Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata, None),
+                Error::<Test>::InvalidContentHash
+            );
+        }
+    });
+}
+
+#[test]
+fn cannot_create_submission_with_duplicate_tags() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        let mut metadata = metadata();
+        metadata.tags = BoundedVec::try_from(vec![tag(b"news"), tag(b"science"), tag(b"news")]).unwrap();
+        assert_noop!(
+            Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata, None),
+            Error::<Test>::DuplicateTag
+        );
+    });
+}
+
+#[test]
+fn resubmission_replaces_metadata_and_tag_index() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        assert_ok!(Infostratus::withdraw_submission(RuntimeOrigin::signed(1), resource()));
+        let mut updated = metadata();
+        updated.tags = BoundedVec::try_from(vec![tag(b"science")]).unwrap();
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), updated.clone(), None));
+        assert_eq!(Infostratus::submission_metadata(1, resource()), Some(updated));
+        assert_eq!(SubmissionsByTag::<Test>::get(tag(b"news"), (1, resource())), None);
+        assert_eq!(SubmissionsByTag::<Test>::get(tag(b"science"), (1, resource())), Some(()));
+    });
+}
+
+fn panel_submission(slots: u32, quorum: u32) {
+    let _ = Balances::deposit_creating(&1, 100);
+    let _ = Balances::deposit_creating(&2, 100);
+    assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), Some(40)));
+    assert_ok!(Infostratus::configure_review(RuntimeOrigin::signed(1), resource(), slots, quorum));
+    assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()));
+    assert_ok!(Infostratus::complete_assignment(RuntimeOrigin::signed(2), resource()));
+    for reviewer in 3..6 {
+        let _ = Balances::deposit_creating(&reviewer, 100);
+    }
+}
+
+#[test]
+fn review_panel_must_be_a_majority_within_limits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        for (slots, quorum) in [(4, 3), (2, 1), (3, 4), (3, 0)] {
+            assert_noop!(
+                Infostratus::configure_review(RuntimeOrigin::signed(1), resource(), slots, quorum),
+                Error::<Test>::InvalidReviewPanel
+            );
+        }
+        assert_ok!(Infostratus::configure_review(RuntimeOrigin::signed(1), resource(), 3, 2));
+        System::assert_last_event(
+            crate::Event::ReviewPanelConfigured { poster: 1, resource_location: resource(), slots: 3, quorum: 2 }.into()
+        );
+        assert_eq!(Infostratus::review_panels(1, resource()).map(|panel| (panel.slots, panel.quorum)), Some((3, 2)));
+        assert_ok!(Infostratus::configure_review(RuntimeOrigin::signed(1), resource(), 0, 0));
+        assert!(Infostratus::review_panels(1, resource()).is_none());
+    });
+}
+
+#[test]
+fn quorum_of_approvals_completes_submission() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        panel_submission(3, 2);
+        assert_noop!(
+            Infostratus::review_submission(RuntimeOrigin::signed(1), resource(), true),
+            Error::<Test>::ReviewedByPanel
+        );
+        assert_ok!(Infostratus::submit_verdict(RuntimeOrigin::signed(3), 1, resource(), true, None));
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::InReview);
+        assert_ok!(Infostratus::submit_verdict(RuntimeOrigin::signed(4), 1, resource(), false, None));
+        assert_ok!(Infostratus::submit_verdict(RuntimeOrigin::signed(5), 1, resource(), true, None));
+        System::assert_has_event(
+            crate::Event::ReviewConcluded {
+                poster: 1,
+                resource_location: resource(),
+                approvals: 2,
+                rejections: 1,
+                approved: Some(true),
+            }
+            .into()
+        );
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Completed);
+        assert_eq!(Balances::free_balance(2), 140);
+        assert_eq!(held_deposit(1), 0);
+        assert_eq!(held_deposit(2), 0);
+    });
+}
+
+#[test]
+fn quorum_of_rejections_rejects_submission() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        panel_submission(3, 2);
+        let comment = metadata().content_hash;
+        assert_ok!(Infostratus::submit_verdict(RuntimeOrigin::signed(3), 1, resource(), false, Some(comment.clone())));
+        assert_ok!(Infostratus::submit_verdict(RuntimeOrigin::signed(4), 1, resource(), false, None));
+        let submission = Infostratus::submissions_list(1, resource()).unwrap();
+        assert_eq!(submission.status, SubmissionStatus::Rejected);
+        assert_eq!(submission.dispute_deadline, Some(6));
+        let panel = Infostratus::review_panels(1, resource()).unwrap();
+        assert_eq!(panel.verdicts[0].comment_hash, Some(comment));
+        assert_eq!(panel.tally(), (0, 2));
+    });
+}
+
+#[test]
+fn split_panel_disputes_submission() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        panel_submission(2, 2);
+        assert_ok!(Infostratus::submit_verdict(RuntimeOrigin::signed(3), 1, resource(), true, None));
+        assert_ok!(Infostratus::submit_verdict(RuntimeOrigin::signed(4), 1, resource(), false, None));
+        System::assert_last_event(
+            crate::Event::ReviewConcluded {
+                poster: 1,
+                resource_location: resource(),
+                approvals: 1,
+                rejections: 1,
+                approved: None,
+            }
+            .into()
+        );
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Disputed);
+        assert_ok!(Infostratus::resolve_dispute(RuntimeOrigin::root(), 1, resource(), true));
+        assert_eq!(Balances::free_balance(2), 140);
+    });
+}
+
+#[test]
+fn verdicts_are_limited_to_outsiders_once_each() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        panel_submission(3, 3);
+        for participant in [1, 2] {
+            assert_noop!(
+                Infostratus::submit_verdict(RuntimeOrigin::signed(participant), 1, resource(), true, None),
+                Error::<Test>::ReviewerIsParticipant
+            );
+        }
+        assert_noop!(
+            Infostratus::submit_verdict(
+                RuntimeOrigin::signed(3),
+                1,
+                resource(),
+                true,
+                Some(BoundedVec::try_from(vec![0x12, 0x20]).unwrap())
+            ),
+            Error::<Test>::InvalidContentHash
+        );
+        assert_ok!(Infostratus::submit_verdict(RuntimeOrigin::signed(3), 1, resource(), true, None));
+        assert_noop!(
+            Infostratus::submit_verdict(RuntimeOrigin::signed(3), 1, resource(), false, None),
+            Error::<Test>::AlreadyReviewed
+        );
+    });
+}
+
+#[test]
+fn cannot_submit_verdict_without_panel_or_before_review() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        bounty_submission(40);
+        let _ = Balances::deposit_creating(&3, 100);
+        assert_noop!(
+            Infostratus::submit_verdict(RuntimeOrigin::signed(3), 1, resource(), true, None),
+            Error::<Test>::NoReviewPanel
+        );
+        assert_noop!(
+            Infostratus::configure_review(RuntimeOrigin::signed(1), resource(), 1, 1),
+            Error::<Test>::InvalidSubmissionStatus
+        );
+    });
+}
+
+#[test]
+fn chosen_issuer_gates_assignees() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        let _ = Balances::deposit_creating(&2, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        assert_ok!(Infostratus::set_reviewer_issuer(RuntimeOrigin::signed(1), resource(), Some(7)));
+        System::assert_last_event(
+            crate::Event::ReviewerIssuerSet { poster: 1, resource_location: resource(), issuer: Some(7) }.into()
+        );
+        assert_noop!(
+            Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()),
+            Error::<Test>::NotEligible
+        );
+        certify(8, 2);
+        assert_noop!(
+            Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()),
+            Error::<Test>::NotEligible
+        );
+        certify(7, 2);
+        assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()));
+    });
+}
+
+#[test]
+fn trust_from_poster_makes_reviewer_eligible() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        let _ = Balances::deposit_creating(&2, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        assert_ok!(Infostratus::set_reviewer_issuer(RuntimeOrigin::signed(1), resource(), Some(7)));
+        trust(1, 2);
+        assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()));
+    });
+}
+
+#[test]
+fn chosen_issuer_gates_panel_reviewers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        panel_submission(3, 2);
+        assert_ok!(Infostratus::set_reviewer_issuer(RuntimeOrigin::signed(1), resource(), Some(7)));
+        assert_noop!(
+            Infostratus::submit_verdict(RuntimeOrigin::signed(3), 1, resource(), true, None),
+            Error::<Test>::NotEligible
+        );
+        assert_ok!(Infostratus::set_reviewer_issuer(RuntimeOrigin::signed(1), resource(), None));
+        assert_eq!(Infostratus::reviewer_issuers(1, resource()), None);
+        assert_ok!(Infostratus::submit_verdict(RuntimeOrigin::signed(3), 1, resource(), true, None));
+    });
+}
+
+#[test]
+fn only_poster_of_unfinished_submission_can_set_issuer() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        assert_noop!(
+            Infostratus::set_reviewer_issuer(RuntimeOrigin::signed(2), resource(), Some(7)),
+            Error::<Test>::SubmissionDoesNotExist
+        );
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        assert_ok!(Infostratus::withdraw_submission(RuntimeOrigin::signed(1), resource()));
+        assert_noop!(
+            Infostratus::set_reviewer_issuer(RuntimeOrigin::signed(1), resource(), Some(7)),
+            Error::<Test>::InvalidSubmissionStatus
+        );
+    });
+}
+
+#[test]
+fn open_submissions_index_follows_status() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        let _ = Balances::deposit_creating(&2, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        assert!(OpenSubmissions::<Test>::contains_key(1, resource()));
+        assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()));
+        assert!(!OpenSubmissions::<Test>::contains_key(1, resource()));
+        assert_eq!(Infostratus::open_submissions(None, 10), vec![]);
+        assert_eq!(Infostratus::assignments_of(&2, None, 10), vec![(resource(), 1)]);
+        assert_ok!(Infostratus::release_assignment(RuntimeOrigin::signed(2), resource()));
+        assert!(OpenSubmissions::<Test>::contains_key(1, resource()));
+        assert_eq!(Infostratus::assignments_of(&2, None, 10), vec![]);
+        assert_ok!(Infostratus::withdraw_submission(RuntimeOrigin::signed(1), resource()));
+        assert!(!OpenSubmissions::<Test>::contains_key(1, resource()));
+    });
+}
+
+#[test]
+fn open_submissions_and_assignments_are_paginated() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        let _ = Balances::deposit_creating(&2, 100);
+        let resources: Vec<_> = (0..5u8).map(|i| BoundedVec::try_from(vec![i]).unwrap()).collect();
+        for resource in &resources {
+            assert_ok!(Infostratus::create_submission_entry(
+                RuntimeOrigin::signed(1),
+                resource.clone(),
+                metadata(),
+                None
+            ));
+        }
+
+        let first = Infostratus::open_submissions(None, 2);
+        assert_eq!(first.len(), 2);
+        let (poster, last, _) = first.last().unwrap().clone();
+        let rest = Infostratus::open_submissions(Some((poster, last)), 10);
+        assert_eq!(rest.len(), 3);
+        let mut listed: Vec<_> = first.iter().chain(rest.iter()).map(|(_, resource, _)| resource.clone()).collect();
+        listed.sort();
+        assert_eq!(listed, resources);
+
+        for resource in &resources[..3] {
+            assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource.clone()));
+        }
+        assert_eq!(Infostratus::open_submissions(None, 10).len(), 2);
+        let first = Infostratus::assignments_of(&2, None, 2);
+        assert_eq!(first.len(), 2);
+        let rest = Infostratus::assignments_of(&2, Some(first[1].0.clone()), 10);
+        assert_eq!(rest.len(), 1);
+        assert!(first.iter().all(|(resource, _)| *resource != rest[0].0));
+    });
+}
+
+fn sweep(now: u64) {
+    System::set_block_number(now);
+    Infostratus::on_idle(now, Weight::MAX);
+}
+
+#[test]
+fn open_submission_expires_after_its_ttl() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), Some(40)));
+        assert_eq!(held_bounty(1), 40);
+        sweep(100);
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Open);
+        sweep(101);
+        System::assert_has_event(
+            crate::Event::SubmissionExpired { poster: 1, resource_location: resource() }.into()
+        );
+        let submission = Infostratus::submissions_list(1, resource()).unwrap();
+        assert_eq!(submission.status, SubmissionStatus::Expired);
+        assert_eq!((submission.deposit, submission.bounty), (0, 0));
+        assert_eq!(held_deposit(1), 0);
+        assert_eq!(held_bounty(1), 0);
+        assert!(!OpenSubmissions::<Test>::contains_key(1, resource()));
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+    });
+}
+
+#[test]
+fn missed_assignment_deadline_reopens_submission() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assigned_submission();
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().assignment_deadline, Some(21));
+        sweep(21);
+        System::assert_has_event(
+            crate::Event::AssignmentExpired { poster: 1, resource_location: resource(), who: 2 }.into()
+        );
+        let submission = Infostratus::submissions_list(1, resource()).unwrap();
+        assert_eq!(submission.status, SubmissionStatus::Open);
+        assert_eq!((submission.assignee, submission.assignment_deadline), (None, None));
+        assert_eq!(Infostratus::assignments_list(2, resource()), None);
+        assert_eq!(held_deposit(2), 0);
+        assert_eq!(held_deposit(1), 10);
+        assert!(OpenSubmissions::<Test>::contains_key(1, resource()));
+        sweep(101);
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Expired);
+    });
+}
+
+#[test]
+fn handed_in_work_does_not_expire() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assigned_submission();
+        assert_ok!(Infostratus::complete_assignment(RuntimeOrigin::signed(2), resource()));
+        sweep(200);
+        let submission = Infostratus::submissions_list(1, resource()).unwrap();
+        assert_eq!(submission.status, SubmissionStatus::InReview);
+        assert_eq!(held_deposit(2), 10);
+    });
+}
+
+#[test]
+fn submission_released_after_its_ttl_expires_right_away() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        let _ = Balances::deposit_creating(&2, 100);
+        assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource(), metadata(), None));
+        sweep(95);
+        assert_ok!(Infostratus::request_submission_assignment(RuntimeOrigin::signed(2), 1, resource()));
+        sweep(109);
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Assigned);
+        System::set_block_number(110);
+        assert_ok!(Infostratus::release_assignment(RuntimeOrigin::signed(2), resource()));
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().expires_at, 110);
+        Infostratus::on_idle(110, Weight::MAX);
+        assert_eq!(Infostratus::submissions_list(1, resource()).unwrap().status, SubmissionStatus::Expired);
+        assert_eq!(held_deposit(1), 0);
+    });
+}
+
+#[test]
+fn sweep_is_bounded_by_remaining_weight() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let _ = Balances::deposit_creating(&1, 100);
+        for i in 0..3u8 {
+            let resource = BoundedVec::try_from(vec![i]).unwrap();
+            assert_ok!(Infostratus::create_submission_entry(RuntimeOrigin::signed(1), resource, metadata(), None));
+        }
+        sweep(100);
+        assert_eq!(ExpiryCursor::<Test>::get(), Some(101));
+
+        System::set_block_number(101);
+        let db: frame_support::weights::RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+        let expiry = <() as crate::WeightInfo>::expire_submission().max(<() as crate::WeightInfo>::expire_assignment());
+        let limit = db.reads_writes(1, 1) + db.reads(1) + expiry;
+        assert_eq!(Infostratus::on_idle(101, limit), limit);
+        let expired = |n| {
+            (0..3u8)
+                .filter(|i| {
+                    Infostratus::submissions_list(1, BoundedVec::try_from(vec![*i]).unwrap()).unwrap().status
+                        == SubmissionStatus::Expired
+                })
+                .count()
+                == n
+        };
+        assert!(expired(1));
+        assert_eq!(ExpiryCursor::<Test>::get(), Some(101));
+        assert_eq!(Infostratus::on_idle(101, Weight::zero()), Weight::zero());
+        assert!(expired(1));
+        Infostratus::on_idle(101, Weight::MAX);
+        assert!(expired(3));
+        assert_eq!(ExpiryCursor::<Test>::get(), Some(102));
+    });
+}
+
+#[test]
+fn migration_translates_submissions_and_moves_locks_to_holds() {
+    use crate::{migrations, AssignmentsList, ExpiryQueue, SubmissionsList};
+    use frame_support::traits::{
+        GetStorageVersion, LockableCurrency, OnRuntimeUpgrade, StorageVersion, WithdrawReasons,
+    };
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        StorageVersion::new(0).put::<Infostratus>();
+        for (account, balance) in [(1u64, 100), (2, 100), (3, 15), (4, 100)] {
+            let _ = Balances::deposit_creating(&account, balance);
+            Balances::set_lock(InfostratusLockId::get(), &account, 10, WithdrawReasons::all());
+        }
+        let location = |name: &[u8]| BoundedVec::<u8, ConstU32<1024>>::try_from(name.to_vec()).unwrap();
+        // Submissions stored as whether they are assigned, assignments stored as `true`, all
+        // behind one lock per account
+        for (poster, name, assigned) in [(1u64, b"A", false), (1, b"B", true), (3, b"B", false), (3, b"C", true)] {
+            frame_support::storage::unhashed::put(
+                &SubmissionsList::<Test>::hashed_key_for(poster, location(name)),
+                &assigned,
+            );
+        }
+        for (assignee, name) in [(2u64, b"B"), (4, b"D")] {
+            frame_support::storage::unhashed::put(&AssignmentsList::<Test>::hashed_key_for(assignee, location(name)), &true);
+        }
+
+        migrations::v1::MigrateToV1::<Test, Balances, InfostratusLockId>::on_runtime_upgrade();
+
+        assert_eq!(Infostratus::on_chain_storage_version(), StorageVersion::new(1));
+        let open = Infostratus::submissions_list(1, location(b"A")).unwrap();
+        assert_eq!(open.status, SubmissionStatus::Open);
+        assert_eq!(open.created_at, 5);
+        assert_eq!(open.expires_at, 5 + SubmissionTtl::get());
+        assert!(OpenSubmissions::<Test>::contains_key(1, location(b"A")));
+        assert!(ExpiryQueue::<Test>::contains_key(5 + SubmissionTtl::get(), (1, location(b"A"))));
+        let assigned = Infostratus::submissions_list(1, location(b"B")).unwrap();
+        assert_eq!(assigned.status, SubmissionStatus::Assigned);
+        assert_eq!(assigned.assignee, Some(2));
+        assert_eq!(assigned.assignment_deadline, Some(5 + AssignmentPeriod::get()));
+        assert!(!OpenSubmissions::<Test>::contains_key(1, location(b"B")));
+        assert!(ExpiryQueue::<Test>::contains_key(5 + AssignmentPeriod::get(), (1, location(b"B"))));
+        assert_eq!(Infostratus::assignments_list(2, location(b"B")), Some(1));
+        assert_eq!(Infostratus::submissions_list(3, location(b"B")).unwrap().status, SubmissionStatus::Open);
+        // An assigned submission whose assignee is unknown is open again
+        assert_eq!(Infostratus::submissions_list(3, location(b"C")).unwrap().status, SubmissionStatus::Open);
+        assert!(OpenSubmissions::<Test>::contains_key(3, location(b"C")));
+        // An assignment without an assigned submission is dropped
+        assert_eq!(Infostratus::assignments_list(4, location(b"D")), None);
+        assert_eq!(ExpiryCursor::<Test>::get(), Some(5));
+        for account in 1..=4 {
+            assert!(Balances::locks(&account).is_empty());
+        }
+        let reason: RuntimeHoldReason = HoldReason::SubmissionDeposit.into();
+        assert_eq!(Balances::balance_on_hold(&reason, &1), 20);
+        assert_eq!(Balances::balance_on_hold(&reason, &2), 10);
+        assert_eq!(assigned.deposit, 10);
+        assert_eq!(assigned.assignee_deposit, 10);
+        // Only one of the deposits of account 3 could be held
+        assert_eq!(Balances::balance_on_hold(&reason, &3), 10);
+        let mut deposits = [b"B", b"C"].map(|name| Infostratus::submissions_list(3, location(name)).unwrap().deposit);
+        deposits.sort();
+        assert_eq!(deposits, [0, 10]);
+        assert_eq!(Balances::balance_on_hold(&reason, &4), 0);
+
+        // The assignee can hand in their work as usual
+        assert_ok!(Infostratus::complete_assignment(RuntimeOrigin::signed(2), location(b"B")));
+        assert_eq!(Infostratus::submissions_list(1, location(b"B")).unwrap().status, SubmissionStatus::InReview);
+
+        // Withdrawing releases exactly the submission's share
+        assert_ok!(Infostratus::withdraw_submission(RuntimeOrigin::signed(1), location(b"A")));
+        assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+
+        // Running the migration again changes nothing
+        migrations::v1::MigrateToV1::<Test, Balances, InfostratusLockId>::on_runtime_upgrade();
+        assert_eq!(Infostratus::submissions_list(1, location(b"B")).unwrap().status, SubmissionStatus::InReview);
+        assert_eq!(Balances::balance_on_hold(&reason, &1), 10);
+    });
+}
@@ -1,13 +1,12 @@
 
-//! Autogenerated weights for `pallet_infostratus`
+//! Weights for `pallet_infostratus`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
-//! DATE: 2025-05-11, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `DESKTOP-AH3IE72`, CPU: `AMD Ryzen 3 3100 4-Core Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! NONE OF THESE WEIGHTS HAS BEEN BENCHMARKED AGAINST THE CURRENT CALLS. They are hand-written
+//! estimates derived from the storage each call accesses, listed above each function. Replace
+//! this file with the output of the command below, run against the benchmarks in
+//! `benchmarking.rs`, before relying on them.
 
-// Executed Command:
+// Command to regenerate this file:
 // frame-omni-bencher
 // v1
 // benchmark
@@ -17,7 +16,7 @@
 // --pallet
 // pallet_infostratus
 // --extrinsic
-// 
+// *
 // --template
 // /home/neurosx/WORKING_WORKSPACE/StandaloneSolochain2503/solochain/pallets/benchmarking/frame-weight-template.hbs
 // --output
@@ -33,79 +32,325 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_infostratus`.
 pub trait WeightInfo {
-	fn create_submission_entry() -> Weight;
+	fn create_submission_entry(t: u32, ) -> Weight;
 	fn request_submission_assignment() -> Weight;
+	fn withdraw_submission() -> Weight;
+	fn complete_assignment() -> Weight;
+	fn release_assignment() -> Weight;
+	fn review_submission() -> Weight;
+	fn dispute_submission() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn settle_submission() -> Weight;
+	fn configure_review() -> Weight;
+	fn submit_verdict() -> Weight;
+	fn set_reviewer_issuer() -> Weight;
+	fn expire_submission() -> Weight;
+	fn expire_assignment() -> Weight;
 }
 
 /// Weights for `pallet_infostratus` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
-	/// Proof: `Infostratus::SubmissionsList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn create_submission_entry() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `6`
-		//  Estimated: `4764`
-		// Minimum execution time: 51_631_000 picoseconds.
-		Weight::from_parts(61_180_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Infostratus::SubmissionMetadataList` (r:1 w:1)
+	/// Storage: `Infostratus::SubmissionsByTag` (r:0 w:32)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	/// Storage: `Infostratus::ExpiryCursor` (r:1 w:0)
+	/// Storage: `Infostratus::ExpiryQueue` (r:0 w:1)
+	/// The range of component `t` is `[0, 16]`.
+	fn create_submission_entry(t: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(66_913_402, 4362)
+			.saturating_add(Weight::from_parts(4_382_551, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(t.into())))
 	}
 	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
-	/// Proof: `Infostratus::SubmissionsList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Storage: `Infostratus::AssignmentsList` (r:1 w:1)
-	/// Proof: `Infostratus::AssignmentsList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Infostratus::ReviewerIssuers` (r:1 w:0)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	/// Storage: `Infostratus::ExpiryCursor` (r:1 w:0)
+	/// Storage: `Infostratus::ExpiryQueue` (r:0 w:1)
 	fn request_submission_assignment() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `115`
-		//  Estimated: `4764`
-		// Minimum execution time: 60_963_000 picoseconds.
+		// Hand-written estimate, not benchmarked.
 		Weight::from_parts(72_212_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	fn withdraw_submission() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(39_870_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Infostratus::AssignmentsList` (r:1 w:0)
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	fn complete_assignment() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(18_640_000, 3629)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Infostratus::AssignmentsList` (r:1 w:1)
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	/// Storage: `Infostratus::ExpiryCursor` (r:1 w:0)
+	/// Storage: `Infostratus::ExpiryQueue` (r:0 w:1)
+	fn release_assignment() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(46_720_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Infostratus::AssignmentsList` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn review_submission() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(104_930_000, 8538)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	fn dispute_submission() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(16_520_000, 3662)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Infostratus::AssignmentsList` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn resolve_dispute() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(107_450_000, 8538)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn settle_submission() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(37_980_000, 3662)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:0)
+	/// Storage: `Infostratus::ReviewPanels` (r:0 w:1)
+	fn configure_review() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(17_120_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Infostratus::ReviewPanels` (r:1 w:1)
+	/// Storage: `Infostratus::AssignmentsList` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Storage: `Infostratus::ReviewerIssuers` (r:1 w:0)
+	fn submit_verdict() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(126_340_000, 8196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:0)
+	/// Storage: `Infostratus::ReviewerIssuers` (r:0 w:1)
+	fn set_reviewer_issuer() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(16_730_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn expire_submission() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(42_610_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Infostratus::AssignmentsList` (r:0 w:1)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	/// Storage: `Infostratus::ExpiryCursor` (r:1 w:0)
+	/// Storage: `Infostratus::ExpiryQueue` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn expire_assignment() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(49_180_000, 4764)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
-	/// Proof: `Infostratus::SubmissionsList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn create_submission_entry() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `6`
-		//  Estimated: `4764`
-		// Minimum execution time: 51_631_000 picoseconds.
-		Weight::from_parts(61_180_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Infostratus::SubmissionMetadataList` (r:1 w:1)
+	/// Storage: `Infostratus::SubmissionsByTag` (r:0 w:32)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	/// Storage: `Infostratus::ExpiryCursor` (r:1 w:0)
+	/// Storage: `Infostratus::ExpiryQueue` (r:0 w:1)
+	/// The range of component `t` is `[0, 16]`.
+	fn create_submission_entry(t: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(66_913_402, 4362)
+			.saturating_add(Weight::from_parts(4_382_551, 0).saturating_mul(t.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(t.into())))
 	}
 	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
-	/// Proof: `Infostratus::SubmissionsList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Storage: `Infostratus::AssignmentsList` (r:1 w:1)
-	/// Proof: `Infostratus::AssignmentsList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Infostratus::ReviewerIssuers` (r:1 w:0)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	/// Storage: `Infostratus::ExpiryCursor` (r:1 w:0)
+	/// Storage: `Infostratus::ExpiryQueue` (r:0 w:1)
 	fn request_submission_assignment() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `115`
-		//  Estimated: `4764`
-		// Minimum execution time: 60_963_000 picoseconds.
+		// Hand-written estimate, not benchmarked.
 		Weight::from_parts(72_212_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	fn withdraw_submission() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(39_870_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Infostratus::AssignmentsList` (r:1 w:0)
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	fn complete_assignment() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(18_640_000, 3629)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Infostratus::AssignmentsList` (r:1 w:1)
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	/// Storage: `Infostratus::ExpiryCursor` (r:1 w:0)
+	/// Storage: `Infostratus::ExpiryQueue` (r:0 w:1)
+	fn release_assignment() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(46_720_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Infostratus::AssignmentsList` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn review_submission() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(104_930_000, 8538)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	fn dispute_submission() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(16_520_000, 3662)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Infostratus::AssignmentsList` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn resolve_dispute() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(107_450_000, 8538)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn settle_submission() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(37_980_000, 3662)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:0)
+	/// Storage: `Infostratus::ReviewPanels` (r:0 w:1)
+	fn configure_review() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(17_120_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Infostratus::ReviewPanels` (r:1 w:1)
+	/// Storage: `Infostratus::AssignmentsList` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Storage: `Infostratus::ReviewerIssuers` (r:1 w:0)
+	fn submit_verdict() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(126_340_000, 8196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:0)
+	/// Storage: `Infostratus::ReviewerIssuers` (r:0 w:1)
+	fn set_reviewer_issuer() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(16_730_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn expire_submission() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(42_610_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Infostratus::SubmissionsList` (r:1 w:1)
+	/// Storage: `Infostratus::AssignmentsList` (r:0 w:1)
+	/// Storage: `Infostratus::OpenSubmissions` (r:0 w:1)
+	/// Storage: `Infostratus::ExpiryCursor` (r:1 w:0)
+	/// Storage: `Infostratus::ExpiryQueue` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn expire_assignment() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(49_180_000, 4764)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
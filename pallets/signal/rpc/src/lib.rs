@@ -0,0 +1,311 @@
+//! RPC interface for the signal pallet.
+//!
+//! Besides querying payloads, applications can subscribe to the signals sent in each new best
+//! block with `signal_subscribe`, narrowed down by a filter so that they no longer need to watch
+//! every event of the chain. The subscription follows block imports rather than the canonical
+//! chain, so it does not replay or retract signals across a reorg.
+
+use std::{pin::Pin, sync::Arc};
+
+use codec::Codec;
+use futures::{
+	future::{self, Either},
+	Stream, StreamExt,
+};
+use jsonrpsee::{
+	core::{RpcResult, SubscriptionResult},
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+	PendingSubscriptionSink, SubscriptionMessage,
+};
+use pallet_signal_runtime_api::{SignalKind, SignalRecord};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+
+pub use pallet_signal_runtime_api::SignalApi as SignalRuntimeApi;
+
+/// The log target of this crate.
+const LOG_TARGET: &str = "rpc::signal";
+
+/// A stream of the hashes of blocks imported as the new best block.
+pub type BestBlockImports<Hash> = Pin<Box<dyn Stream<Item = Hash> + Send>>;
+
+/// Narrows down the signals streamed to a subscriber. Signals must match every criterion given.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SignalFilter<AccountId> {
+	/// Only service signals for this service identifier.
+	pub service_identifier: Option<Bytes>,
+	/// Only signals sent by one of these accounts, or by any account if empty.
+	pub senders: Vec<AccountId>,
+	/// Only signals whose payload starts with these bytes.
+	pub payload_prefix: Option<Bytes>,
+}
+
+impl<AccountId> Default for SignalFilter<AccountId> {
+	fn default() -> Self {
+		Self { service_identifier: None, senders: Vec::new(), payload_prefix: None }
+	}
+}
+
+impl<AccountId: PartialEq> SignalFilter<AccountId> {
+	/// Returns whether `record` passes the filter.
+	pub fn matches<Hash>(&self, record: &SignalRecord<AccountId, Hash>) -> bool {
+		let service_matches = match (&self.service_identifier, &record.kind) {
+			(None, _) => true,
+			(Some(wanted), SignalKind::Service { service_identifier }) => wanted.0 == *service_identifier,
+			(Some(_), _) => false,
+		};
+		let sender_matches = self.senders.is_empty() || self.senders.contains(&record.sender);
+		let payload_matches =
+			self.payload_prefix.as_ref().is_none_or(|prefix| record.payload.starts_with(&prefix.0));
+		service_matches && sender_matches && payload_matches
+	}
+}
+
+/// What a streamed signal was sent as.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum SignalKindEntry<Hash> {
+	/// A generic signal.
+	Signal,
+	/// A signal for a service, whose payload is its URL.
+	Service { service_identifier: Bytes },
+	/// A validated Whiteflag message.
+	Whiteflag,
+	/// A signal referencing the noted payload with `hash`.
+	Payload { hash: Hash },
+}
+
+/// A signal streamed to subscribers.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalNotification<AccountId, Hash> {
+	/// The block the signal was sent in.
+	pub block_number: u64,
+	/// The index of the extrinsic in the block that sent the signal, if it was sent by one.
+	pub extrinsic_index: Option<u32>,
+	/// The account that sent the signal.
+	pub sender: AccountId,
+	/// What the signal was sent as.
+	#[serde(flatten)]
+	pub kind: SignalKindEntry<Hash>,
+	/// The content of the signal.
+	pub payload: Bytes,
+}
+
+impl<AccountId, Hash> SignalNotification<AccountId, Hash> {
+	fn new(block_number: u64, record: SignalRecord<AccountId, Hash>) -> Self {
+		let kind = match record.kind {
+			SignalKind::Signal => SignalKindEntry::Signal,
+			SignalKind::Service { service_identifier } => {
+				SignalKindEntry::Service { service_identifier: Bytes(service_identifier) }
+			},
+			SignalKind::Whiteflag => SignalKindEntry::Whiteflag,
+			SignalKind::Payload { hash } => SignalKindEntry::Payload { hash },
+		};
+		Self {
+			block_number,
+			extrinsic_index: record.extrinsic_index,
+			sender: record.sender,
+			kind,
+			payload: Bytes(record.payload),
+		}
+	}
+}
+
+#[rpc(client, server)]
+pub trait SignalApi<BlockHash, AccountId, Hash> {
+	/// Returns the payload noted under `hash`, or `None` if it was never noted or has been
+	/// unnoted.
+	#[method(name = "signal_payload")]
+	fn payload(&self, hash: Hash, at: Option<BlockHash>) -> RpcResult<Option<Bytes>>;
+
+	/// Streams the signals matching `filter` from every block imported as the new best block,
+	/// or all signals if no filter is given.
+	///
+	/// Only the block that becomes best on import is delivered. After a reorg, signals already
+	/// delivered from retracted blocks are not withdrawn, and blocks of the new best chain that
+	/// were imported before it became best are never delivered. A block whose signals cannot be
+	/// read is logged and skipped without ending the subscription.
+	#[subscription(
+		name = "signal_subscribe" => "signal_signal",
+		unsubscribe = "signal_unsubscribe",
+		item = SignalNotification<AccountId, Hash>
+	)]
+	async fn subscribe_signals(&self, filter: Option<SignalFilter<AccountId>>) -> SubscriptionResult;
+}
+
+/// Provides RPC methods to query and subscribe to signals.
+pub struct Signal<C, B: BlockT> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	/// Opens a stream of new best blocks for each subscription.
+	best_block_imports: Arc<dyn Fn() -> BestBlockImports<B::Hash> + Send + Sync>,
+}
+
+impl<C, B: BlockT> Signal<C, B> {
+	/// Creates a new instance of the Signal Rpc helper, whose subscriptions are fed by the
+	/// streams `best_block_imports` opens.
+	pub fn new(
+		client: Arc<C>,
+		best_block_imports: impl Fn() -> BestBlockImports<B::Hash> + Send + Sync + 'static,
+	) -> Self {
+		Self { client, best_block_imports: Arc::new(best_block_imports) }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The block is not known to the client.
+	UnknownBlock,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::UnknownBlock => 2,
+		}
+	}
+}
+
+fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+}
+
+/// Returns the signals sent in the block with `hash` that pass `filter`.
+fn block_signals<C, Block, AccountId, Hash>(
+	client: &C,
+	hash: Block::Hash,
+	filter: &SignalFilter<AccountId>,
+) -> RpcResult<Vec<SignalNotification<AccountId, Hash>>>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: SignalRuntimeApi<Block, AccountId, Hash, NumberFor<Block>>,
+	AccountId: Codec + PartialEq,
+	Hash: Codec,
+	NumberFor<Block>: Into<u64>,
+{
+	let block_number = client
+		.number(hash)
+		.map_err(|e| map_err(e, "Unable to query block number."))?
+		.ok_or_else(|| ErrorObject::owned(Error::UnknownBlock.into(), "Unknown block.", None::<()>))?;
+	let records = client
+		.runtime_api()
+		.block_signals(hash)
+		.map_err(|e| map_err(e, "Unable to query block signals."))?;
+	Ok(records
+		.into_iter()
+		.filter(|record| filter.matches(record))
+		.map(|record| SignalNotification::new(block_number.into(), record))
+		.collect())
+}
+
+#[jsonrpsee::core::async_trait]
+impl<C, Block, AccountId, Hash> SignalApiServer<<Block as BlockT>::Hash, AccountId, Hash> for Signal<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: SignalRuntimeApi<Block, AccountId, Hash, NumberFor<Block>>,
+	AccountId: Codec + PartialEq + Serialize + Send + Sync + 'static,
+	Hash: Codec + Serialize + Send + Sync + 'static,
+	NumberFor<Block>: Into<u64>,
+{
+	fn payload(&self, hash: Hash, at: Option<Block::Hash>) -> RpcResult<Option<Bytes>> {
+		let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+		let payload = self
+			.client
+			.runtime_api()
+			.payload(at_hash, hash)
+			.map_err(|e| map_err(e, "Unable to query signal payload."))?;
+		Ok(payload.map(Bytes))
+	}
+
+	async fn subscribe_signals(
+		&self,
+		pending: PendingSubscriptionSink,
+		filter: Option<SignalFilter<AccountId>>,
+	) -> SubscriptionResult {
+		let filter = filter.unwrap_or_default();
+		let mut imports = (self.best_block_imports)();
+		let sink = pending.accept().await?;
+		let closed = sink.closed();
+		futures::pin_mut!(closed);
+		while let Either::Left((Some(hash), _)) = future::select(imports.next(), closed.as_mut()).await {
+			let notifications = match block_signals(&*self.client, hash, &filter) {
+				Ok(notifications) => notifications,
+				Err(error) => {
+					log::warn!(target: LOG_TARGET, "Skipping signals of block {hash:?}: {error}");
+					continue;
+				},
+			};
+			for notification in notifications {
+				sink.send(SubscriptionMessage::from_json(&notification)?).await?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn record(sender: u64, kind: SignalKind<u32>, payload: &[u8]) -> SignalRecord<u64, u32> {
+		SignalRecord { extrinsic_index: Some(1), sender, kind, payload: payload.to_vec() }
+	}
+
+	#[test]
+	fn empty_filter_matches_every_signal() {
+		let filter = SignalFilter::default();
+		assert!(filter.matches(&record(1, SignalKind::Signal, b"")));
+		assert!(filter.matches(&record(2, SignalKind::Payload { hash: 7 }, b"WF1")));
+	}
+
+	#[test]
+	fn service_identifier_matches_only_signals_for_that_service() {
+		let filter = SignalFilter::<u64> { service_identifier: Some(Bytes(b"app".to_vec())), ..Default::default() };
+		let service = |identifier: &[u8]| SignalKind::Service { service_identifier: identifier.to_vec() };
+		assert!(filter.matches(&record(1, service(b"app"), b"https://")));
+		assert!(!filter.matches(&record(1, service(b"other"), b"https://")));
+		assert!(!filter.matches(&record(1, SignalKind::Signal, b"app")));
+	}
+
+	#[test]
+	fn senders_and_payload_prefix_must_both_match() {
+		let filter = SignalFilter { senders: vec![1, 2], payload_prefix: Some(Bytes(b"WF".to_vec())), ..Default::default() };
+		assert!(filter.matches(&record(2, SignalKind::Whiteflag, b"WF1")));
+		assert!(!filter.matches(&record(3, SignalKind::Whiteflag, b"WF1")));
+		assert!(!filter.matches(&record(1, SignalKind::Signal, b"W")));
+	}
+
+	#[test]
+	fn filter_fields_are_optional() {
+		let filter: SignalFilter<u64> = serde_json::from_value(serde_json::json!({ "senders": [5] })).unwrap();
+		assert_eq!(filter, SignalFilter { senders: vec![5], ..Default::default() });
+	}
+
+	#[test]
+	fn notification_serializes_in_camel_case() {
+		let service = SignalKind::Service { service_identifier: vec![0xab] };
+		let notification = SignalNotification::new(12, record(1, service, &[0x01]));
+		assert_eq!(
+			serde_json::to_value(&notification).unwrap(),
+			serde_json::json!({
+				"blockNumber": 12,
+				"extrinsicIndex": 1,
+				"sender": 1,
+				"kind": "service",
+				"serviceIdentifier": "0xab",
+				"payload": "0x01",
+			})
+		);
+	}
+}
@@ -0,0 +1,106 @@
+//! Runtime API definition for the signal pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Statistics of the ratings given to a target on scales from `min` to `max`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct RatingAggregate {
+    /// The lowest rating on the scale.
+    pub min: u8,
+    /// The highest rating on the scale.
+    pub max: u8,
+    /// The number of ratings.
+    pub count: u32,
+    /// The sum of the ratings.
+    pub sum: u64,
+    /// The sum of the squared ratings.
+    pub sum_of_squares: u64,
+    /// The number of ratings in each of a number of equal parts of the scale, lowest first.
+    pub histogram: Vec<u32>,
+}
+
+/// The value of a signal parameter.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum SignalParameterValue {
+    /// A flag.
+    Bool(bool),
+    /// A small unsigned number.
+    U8(u8),
+    /// An unsigned number.
+    U32(u32),
+    /// A large unsigned number.
+    U64(u64),
+    /// A signed number.
+    I64(i64),
+}
+
+/// A signal parameter of an account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SignalParameter<BlockNumber> {
+    /// The name of the parameter.
+    pub name: Vec<u8>,
+    /// The value of the parameter.
+    pub value: SignalParameterValue,
+    /// The block the parameter was last set in.
+    pub changed_at: BlockNumber,
+}
+
+/// What a signal was sent as.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum SignalKind<Hash> {
+    /// A generic signal sent with `send_signal`.
+    Signal,
+    /// A signal for a service sent with `send_service_signal`, whose payload is its URL.
+    Service { service_identifier: Vec<u8> },
+    /// A validated Whiteflag message sent with `send_whiteflag_signal`.
+    Whiteflag,
+    /// A signal referencing the noted payload with `hash`, sent with `send_payload_signal`.
+    Payload { hash: Hash },
+}
+
+/// A signal sent in a block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SignalRecord<AccountId, Hash> {
+    /// The index of the extrinsic that sent the signal, if it was sent by one.
+    pub extrinsic_index: Option<u32>,
+    /// The account that sent the signal.
+    pub sender: AccountId,
+    /// What the signal was sent as.
+    pub kind: SignalKind<Hash>,
+    /// The content of the signal. For payload signals this is the noted payload, or empty if it
+    /// has been unnoted since.
+    pub payload: Vec<u8>,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait SignalApi<AccountId, Hash, BlockNumber>
+    where
+        AccountId: Codec,
+        Hash: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns the aggregated ratings of `target`, one for each bounds of scale it was rated
+        /// on.
+        fn rating_aggregates(target: Vec<u8>) -> Vec<RatingAggregate>;
+
+        /// Returns the aggregated ratings of `target` on scales from `min` to `max`, if any.
+        fn rating_aggregate(target: Vec<u8>, min: u8, max: u8) -> Option<RatingAggregate>;
+
+        /// Returns the noted payload with `hash`, if it has not been unnoted.
+        fn payload(hash: Hash) -> Option<Vec<u8>>;
+
+        /// Returns the signals sent in the block whose state this is called at, in the order
+        /// they were sent.
+        fn block_signals() -> Vec<SignalRecord<AccountId, Hash>>;
+
+        /// Returns all signal parameters `who` has set, ordered by name. Called at a past block,
+        /// it returns the parameters as they were then.
+        fn signal_parameters(who: AccountId) -> Vec<SignalParameter<BlockNumber>>;
+    }
+}
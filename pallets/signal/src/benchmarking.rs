@@ -2,12 +2,19 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use crate::Pallet as Signal;
+use alloc::vec;
+use crate::{pallet::RatingSignalOf, Pallet as Signal};
 use frame_benchmarking::{account as benchmark_account, v2::*};
-use frame_support::{traits::Currency, BoundedVec};
+use frame_support::{traits::fungible::{Inspect, Mutate}, BoundedVec};
 use frame_support::traits::Get;
 use frame_system::RawOrigin;
-use frame_support::sp_runtime::traits::Bounded;
+use frame_support::{
+    dispatch::{DispatchInfo, GetDispatchInfo, PostDispatchInfo},
+    sp_runtime::traits::{
+        AsSystemOriginSigner, AsTransactionAuthorizedOrigin, Bounded, DispatchTransaction, Dispatchable, Hash,
+    },
+    traits::IsSubType,
+};
 
 pub fn get_account<T: Config>(name: &'static str) -> T::AccountId {
     benchmark_account(name, 0, 0)
@@ -17,14 +24,56 @@ pub fn get_account<T: Config>(name: &'static str) -> T::AccountId {
 //     RawOrigin::Signed(get_account::<T>(name))
 // }
 
+fn rating<T: Config>(rating: u8) -> RatingSignalOf<T> {
+    RatingSignal {
+        scale: RatingScale::FiveStar,
+        rating,
+        category: Some(BoundedVec::try_from(vec![b'c'; T::MaxCategoryLength::get() as usize]).unwrap()),
+        comment_hash: Some(BoundedVec::try_from(vec![0xab; T::MaxCommentHashLength::get() as usize]).unwrap()),
+    }
+}
+
+/// Registers `service_identifier` for a fresh owner and authorizes the maximum number of
+/// publishers, the last of which is `last_publisher`.
+fn register_full_service<T: Config>(
+    service_identifier: &BoundedVec<u8, T::MaxSize>,
+    last_publisher: T::AccountId,
+) -> Result<T::AccountId, BenchmarkError> {
+    let owner: T::AccountId = get_account::<T>("//Bob");
+    T::Currency::set_balance(&owner, pallet::BalanceOf::<T>::max_value() / 4u32.into());
+    Signal::<T>::register_service(RawOrigin::Signed(owner.clone()).into(), service_identifier.clone())?;
+    let max_publishers = T::MaxServicePublishers::get();
+    for index in 1..max_publishers {
+        let publisher: T::AccountId = benchmark_account("publisher", index, 0);
+        Signal::<T>::authorize_service_publisher(
+            RawOrigin::Signed(owner.clone()).into(),
+            service_identifier.clone(),
+            publisher,
+        )?;
+    }
+    if max_publishers > 0 {
+        Signal::<T>::authorize_service_publisher(
+            RawOrigin::Signed(owner.clone()).into(),
+            service_identifier.clone(),
+            last_publisher,
+        )?;
+    }
+    Ok(owner)
+}
+
 pub fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
-#[benchmarks]
+#[benchmarks(where
+    T: Send + Sync,
+    <T as frame_system::Config>::RuntimeCall:
+        IsSubType<Call<T>> + From<Call<T>> + Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+    <T as frame_system::Config>::RuntimeOrigin: AsSystemOriginSigner<T::AccountId> + AsTransactionAuthorizedOrigin,
+)]
 mod benchmarks {
     use super::*;
-    type DepositBalanceOf<T> = <<T as pallet::Config>::Currency as Currency<
+    type DepositBalanceOf<T> = <<T as pallet::Config>::Currency as Inspect<
         <T as frame_system::Config>::AccountId,
     >>::Balance;
 
@@ -32,14 +81,36 @@ mod benchmarks {
     fn set_signal_parameter() -> Result<(), BenchmarkError> {
         let name = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"PARAM".to_vec()).unwrap();
         let caller: T::AccountId = get_account::<T>("//Alice");
-        T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::from(10_000u32));
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::from(10_000u32));
+
+        let value = SignalParameterValue::I64(-42);
 
         #[extrinsic_call]
-        _(RawOrigin::Signed(caller.clone()), name.clone(), 42);
+        _(RawOrigin::Signed(caller.clone()), name.clone(), value);
 
-        assert!(SignalParameterList::<T>::contains_key(caller.clone(), name.clone()));
-        assert_eq!(SignalParameterList::<T>::get(caller.clone(), name.clone()), 42);
-        assert_last_event::<T>(Event::SignalParameterSet { who: caller }.into());
+        let parameter = SignalParameterList::<T>::get(caller.clone(), name.clone());
+        assert_eq!(parameter.map(|parameter| parameter.value), Some(value));
+        assert_eq!(SignalParameterCounts::<T>::get(caller.clone()), 1);
+        assert_last_event::<T>(Event::SignalParameterSet { who: caller, name, value }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_signal_parameter() -> Result<(), BenchmarkError> {
+        let name = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"PARAM".to_vec()).unwrap();
+        let caller: T::AccountId = get_account::<T>("//Alice");
+        Signal::<T>::set_signal_parameter(
+            RawOrigin::Signed(caller.clone()).into(),
+            name.clone(),
+            SignalParameterValue::I64(-42),
+        )?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), name.clone());
+
+        assert!(!SignalParameterList::<T>::contains_key(caller.clone(), name.clone()));
+        assert!(!SignalParameterCounts::<T>::contains_key(caller.clone()));
+        assert_last_event::<T>(Event::SignalParameterRemoved { who: caller, name }.into());
         Ok(())
     }
 
@@ -47,15 +118,15 @@ mod benchmarks {
     fn send_rating_signal() -> Result<(), BenchmarkError> {
         let target = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"TARGET".to_vec()).unwrap();
         let caller: T::AccountId = get_account::<T>("//Alice");
-        T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
-        let balance = T::Currency::free_balance(&caller);
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::max_value() / 4u32.into());
+        let balance = T::Currency::balance(&caller);
         frame_support::runtime_print!("send_rating_signal: balance before call: {:?}", balance);
 
         #[extrinsic_call]
-        _(RawOrigin::Signed(caller.clone()), target.clone(), 5);
+        _(RawOrigin::Signed(caller.clone()), target.clone(), rating::<T>(5));
 
         assert!(RatingSignalList::<T>::contains_key(caller.clone(), target.clone()));
-        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()), 5);
+        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()).map(|signal| signal.rating), Some(5));
         frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalLock { account: caller.clone(), amount: T::LockPrice::get().into() }).into());
         frame_system::Pallet::<T>::assert_last_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::RatingSignalSent { who: caller.clone() }).into());
         Ok(())
@@ -65,19 +136,18 @@ mod benchmarks {
     fn update_rating_signal() -> Result<(), BenchmarkError> {
         let target = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"TARGET".to_vec()).unwrap();
         let caller: T::AccountId = get_account::<T>("//Alice");
-        T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
-        let balance = T::Currency::free_balance(&caller);
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::max_value() / 4u32.into());
+        let balance = T::Currency::balance(&caller);
         frame_support::runtime_print!("update_rating_signal: balance before setup call: {:?}", balance);
-        Signal::<T>::send_rating_signal(RawOrigin::Signed(caller.clone()).into(), target.clone(), 5)?;
-        T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
-        let balance = T::Currency::free_balance(&caller);
+        Signal::<T>::send_rating_signal(RawOrigin::Signed(caller.clone()).into(), target.clone(), rating::<T>(5))?;
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::max_value() / 4u32.into());
+        let balance = T::Currency::balance(&caller);
         frame_support::runtime_print!("update_rating_signal: balance before main call: {:?}", balance);
 
         #[extrinsic_call]
-        _(RawOrigin::Signed(caller.clone()), target.clone(), 9);
+        _(RawOrigin::Signed(caller.clone()), target.clone(), rating::<T>(3));
 
-        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()), 9);
-        frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalLockExtended { account: caller.clone(), amount: T::LockPrice::get().into() }).into());
+        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()).map(|signal| signal.rating), Some(3));
         frame_system::Pallet::<T>::assert_last_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::RatingSignalUpdated { who: caller.clone() }).into());
         Ok(())
     }
@@ -86,19 +156,19 @@ mod benchmarks {
     fn revoke_rating_signal() -> Result<(), BenchmarkError> {
         let target = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"TARGET".to_vec()).unwrap();
         let caller: T::AccountId = get_account::<T>("//Alice");
-        T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
-        let balance = T::Currency::free_balance(&caller);
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::max_value() / 4u32.into());
+        let balance = T::Currency::balance(&caller);
         frame_support::runtime_print!("revoke_rating_signal: balance before setup call: {:?}", balance);
-        Signal::<T>::send_rating_signal(RawOrigin::Signed(caller.clone()).into(), target.clone(), 5)?;
-        T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
-        let balance = T::Currency::free_balance(&caller);
+        Signal::<T>::send_rating_signal(RawOrigin::Signed(caller.clone()).into(), target.clone(), rating::<T>(5))?;
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::max_value() / 4u32.into());
+        let balance = T::Currency::balance(&caller);
         frame_support::runtime_print!("revoke_rating_signal: balance before main call: {:?}", balance);
 
         #[extrinsic_call]
         _(RawOrigin::Signed(caller.clone()), target.clone());
 
-        assert_eq!(RatingSignalList::<T>::get(caller.clone(), target.clone()), 0);
-        frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalUnlock { account: caller.clone() }).into());
+        assert!(!RatingSignalList::<T>::contains_key(caller.clone(), target.clone()));
+        frame_system::Pallet::<T>::assert_has_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::SignalUnlock { account: caller.clone(), amount: T::LockPrice::get().into() }).into());
         frame_system::Pallet::<T>::assert_last_event(<T as pallet::Config>::RuntimeEvent::from(Event::<T>::RatingSignalRevoked { who: caller.clone() }).into());
         Ok(())
     }
@@ -107,7 +177,7 @@ mod benchmarks {
     fn send_signal() -> Result<(), BenchmarkError> {
         let signal = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"SIGNAL".to_vec()).unwrap();
         let caller: T::AccountId = get_account::<T>("//Alice");
-        T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::from(10_000u32));
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::from(10_000u32));
 
         #[extrinsic_call]
         _(RawOrigin::Signed(caller.clone()), signal.clone());
@@ -121,7 +191,9 @@ mod benchmarks {
         let service_identifier = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"SERVICE".to_vec()).unwrap();
         let url = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"URL".to_vec()).unwrap();
         let caller: T::AccountId = get_account::<T>("//Alice");
-        T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::from(10_000u32));
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::from(10_000u32));
+        // The worst case is a registered service whose last authorized publisher sends.
+        register_full_service::<T>(&service_identifier, caller.clone())?;
 
         #[extrinsic_call]
         _(RawOrigin::Signed(caller.clone()), service_identifier.clone(), url.clone());
@@ -130,5 +202,185 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn send_whiteflag_signal() -> Result<(), BenchmarkError> {
+        // The longest free text message that fits, as decoding reads the message bit by bit.
+        let text_length = (T::MaxSize::get() as usize).saturating_sub(whiteflag::HEADER_BITS / 8 + 1);
+        let bytes = whiteflag::Message {
+            header: whiteflag::Header {
+                duress: false,
+                message_code: whiteflag::MessageCode::FreeText,
+                reference_indicator: 0,
+                referenced_message: [0; 32],
+            },
+            body: whiteflag::Body::FreeText { text: vec![b'w'; text_length] },
+        }
+        .to_bytes()
+        .map_err(|_| BenchmarkError::Stop("invalid whiteflag message"))?;
+        let message = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(bytes).unwrap();
+        let caller: T::AccountId = get_account::<T>("//Alice");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), message.clone());
+
+        assert_last_event::<T>(
+            Event::WhiteflagSignalSent {
+                who: caller,
+                message_code: whiteflag::MessageCode::FreeText,
+                duress: false,
+                reference_indicator: 0,
+                referenced_message: [0; 32],
+                message,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_service() -> Result<(), BenchmarkError> {
+        let service_identifier = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"SERVICE".to_vec()).unwrap();
+        let caller: T::AccountId = get_account::<T>("//Alice");
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::max_value() / 4u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), service_identifier.clone());
+
+        assert!(Services::<T>::contains_key(&service_identifier));
+        assert_last_event::<T>(Event::ServiceRegistered { service_identifier, owner: caller }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unregister_service() -> Result<(), BenchmarkError> {
+        let service_identifier = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"SERVICE".to_vec()).unwrap();
+        let owner = register_full_service::<T>(&service_identifier, get_account::<T>("//Alice"))?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner.clone()), service_identifier.clone());
+
+        assert!(!Services::<T>::contains_key(&service_identifier));
+        assert_last_event::<T>(Event::ServiceUnregistered { service_identifier, owner }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn authorize_service_publisher() -> Result<(), BenchmarkError> {
+        let service_identifier = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"SERVICE".to_vec()).unwrap();
+        let publisher: T::AccountId = get_account::<T>("//Alice");
+        let owner = register_full_service::<T>(&service_identifier, publisher.clone())?;
+        // Make room for the publisher again, so it is checked against all others before it is added.
+        Signal::<T>::revoke_service_publisher(
+            RawOrigin::Signed(owner.clone()).into(),
+            service_identifier.clone(),
+            publisher.clone(),
+        )?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), service_identifier.clone(), publisher.clone());
+
+        assert_last_event::<T>(Event::ServicePublisherAuthorized { service_identifier, publisher }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_service_publisher() -> Result<(), BenchmarkError> {
+        let service_identifier = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"SERVICE".to_vec()).unwrap();
+        let publisher: T::AccountId = get_account::<T>("//Alice");
+        let owner = register_full_service::<T>(&service_identifier, publisher.clone())?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(owner), service_identifier.clone(), publisher.clone());
+
+        assert_last_event::<T>(Event::ServicePublisherRevoked { service_identifier, publisher }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn note_payload(s: Linear<0, { T::MaxPayloadSize::get() }>) -> Result<(), BenchmarkError> {
+        let payload = BoundedVec::<u8, T::MaxPayloadSize>::try_from(vec![b'p'; s as usize]).unwrap();
+        let hash = T::Hashing::hash(&payload);
+        let caller: T::AccountId = get_account::<T>("//Alice");
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::max_value() / 4u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), payload);
+
+        assert!(Payloads::<T>::contains_key(hash));
+        assert_last_event::<T>(Event::PayloadNoted { hash, who: caller, deposit: Signal::<T>::payload_deposit(s) }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unnote_payload() -> Result<(), BenchmarkError> {
+        let payload = BoundedVec::<u8, T::MaxPayloadSize>::try_from(vec![b'p'; T::MaxPayloadSize::get() as usize]).unwrap();
+        let hash = T::Hashing::hash(&payload);
+        let caller: T::AccountId = get_account::<T>("//Alice");
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::max_value() / 4u32.into());
+        Signal::<T>::note_payload(RawOrigin::Signed(caller.clone()).into(), payload)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), hash);
+
+        assert!(!Payloads::<T>::contains_key(hash));
+        assert_last_event::<T>(Event::PayloadUnnoted { hash, who: caller }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn send_payload_signal() -> Result<(), BenchmarkError> {
+        let payload = BoundedVec::<u8, T::MaxPayloadSize>::try_from(vec![b'p'; T::MaxPayloadSize::get() as usize]).unwrap();
+        let hash = T::Hashing::hash(&payload);
+        let caller: T::AccountId = get_account::<T>("//Alice");
+        T::Currency::set_balance(&caller, DepositBalanceOf::<T>::max_value() / 4u32.into());
+        Signal::<T>::note_payload(RawOrigin::Signed(caller.clone()).into(), payload)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), hash);
+
+        assert_last_event::<T>(Event::PayloadSignalSent { hash, who: caller }.into());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn check_signal_rate() -> Result<(), BenchmarkError> {
+        let signal = BoundedVec::<u8, <T as pallet::Config>::MaxSize>::try_from(b"SIGNAL".to_vec()).unwrap();
+        let caller: T::AccountId = get_account::<T>("//Alice");
+        // The worst case starts a new period, which is scheduled to be pruned.
+        let call: <T as frame_system::Config>::RuntimeCall = Call::<T>::send_signal { signal }.into();
+        let info = call.get_dispatch_info();
+
+        #[block]
+        {
+            CheckSignalRate::<T>::new()
+                .test_run(RawOrigin::Signed(caller.clone()).into(), &call, &info, 0, 0, |_| Ok(().into()))
+                .unwrap()
+                .unwrap();
+        }
+
+        let rate = SignalRates::<T>::get(&caller).ok_or(BenchmarkError::Stop("period not started"))?;
+        assert_eq!(rate.count, 1);
+        assert!(SignalRateExpiry::<T>::contains_key(rate.period_start + T::SignalRatePeriod::get(), &caller));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn prune_signal_rate() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = get_account::<T>("//Alice");
+        let now = frame_system::Pallet::<T>::block_number();
+        let end = now + T::SignalRatePeriod::get();
+        SignalRates::<T>::insert(&caller, SignalRate { period_start: now, count: 1 });
+        SignalRateExpiry::<T>::insert(end, &caller, ());
+
+        #[block]
+        {
+            Pallet::<T>::prune_signal_rate(end, &caller, end);
+        }
+
+        assert!(!SignalRates::<T>::contains_key(&caller));
+        assert!(!SignalRateExpiry::<T>::contains_key(end, &caller));
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
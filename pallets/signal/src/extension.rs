@@ -0,0 +1,108 @@
+//! Transaction extension limiting how many free signals an account may send.
+
+use core::marker::PhantomData;
+
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use frame_support::{
+    dispatch::DispatchInfo,
+    pallet_prelude::TransactionSource,
+    traits::{Get, IsSubType},
+    CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AsSystemOriginSigner, DispatchInfoOf, Dispatchable, TransactionExtension, ValidateResult},
+    transaction_validity::{InvalidTransaction, TransactionValidityError, ValidTransaction},
+    Weight,
+};
+
+use crate::{weights::WeightInfo, Call, Config, Pallet, SignalRate};
+
+/// The custom `InvalidTransaction` code of signals exceeding the sender's rate limit.
+pub const SIGNAL_RATE_LIMITED: u8 = 1;
+
+/// Rejects free signals beyond `MaxSignalsPerPeriod` per account and `SignalRatePeriod` while
+/// transactions are validated, so that excess signals never enter the transaction pool.
+///
+/// Free signals are the ones that only deposit an event: `send_signal`, `send_service_signal`,
+/// `send_whiteflag_signal` and `send_payload_signal`. Other calls pass unchecked.
+///
+/// A signal is counted against the budget only once it is included in a block, so signals
+/// waiting in the transaction pool are not counted and an account may have more of them pending
+/// than it has budget left; the excess is rejected when it is revalidated or dispatched. To keep
+/// such signals from crowding out others, free signals are prioritised by the budget their
+/// sender has left.
+#[derive(Encode, Decode, DecodeWithMemTracking, CloneNoBound, EqNoBound, PartialEqNoBound, DefaultNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckSignalRate<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckSignalRate<T> {
+    /// Creates the extension, which carries no data.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> core::fmt::Debug for CheckSignalRate<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CheckSignalRate")
+    }
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall> for CheckSignalRate<T>
+where
+    T::RuntimeCall: Dispatchable<Info = DispatchInfo> + IsSubType<Call<T>>,
+    <T::RuntimeCall as Dispatchable>::RuntimeOrigin: AsSystemOriginSigner<T::AccountId> + Clone,
+{
+    const IDENTIFIER: &'static str = "CheckSignalRate";
+    type Implicit = ();
+    /// The sender of a free signal and its rate before the signal.
+    type Val = Option<(T::AccountId, SignalRate<BlockNumberFor<T>>)>;
+    type Pre = ();
+
+    fn weight(&self, call: &T::RuntimeCall) -> Weight {
+        match call.is_sub_type() {
+            Some(call) if Pallet::<T>::is_free_signal(call) => T::WeightInfo::check_signal_rate(),
+            _ => Weight::zero(),
+        }
+    }
+
+    fn validate(
+        &self,
+        origin: <T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+        call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+        _self_implicit: Self::Implicit,
+        _inherited_implication: &impl Encode,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        let free_signal = call.is_sub_type().is_some_and(|call| Pallet::<T>::is_free_signal(call));
+        let Some(who) = origin.as_system_origin_signer().filter(|_| free_signal) else {
+            return Ok((ValidTransaction::default(), None, origin));
+        };
+        let rate = Pallet::<T>::signal_rate(who, frame_system::Pallet::<T>::block_number());
+        if rate.count >= T::MaxSignalsPerPeriod::get() {
+            return Err(InvalidTransaction::Custom(SIGNAL_RATE_LIMITED).into());
+        }
+        let remaining = T::MaxSignalsPerPeriod::get().saturating_sub(rate.count);
+        let validity = ValidTransaction { priority: remaining.into(), ..Default::default() };
+        let who = who.clone();
+        Ok((validity, Some((who, rate)), origin))
+    }
+
+    fn prepare(
+        self,
+        val: Self::Val,
+        _origin: &<T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+        _call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        if let Some((who, rate)) = val {
+            Pallet::<T>::record_free_signal(&who, rate);
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
 #[cfg(test)]
@@ -11,22 +13,232 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod extension;
+pub use extension::{CheckSignalRate, SIGNAL_RATE_LIMITED};
+
+pub mod migrations;
 pub mod weights;
+pub mod whiteflag;
 pub use weights::*;
 
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::{Get, RuntimeDebug},
+    BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+
+/// The scale a rating is given on, so that applications sharing the rating signals read each
+/// other's numbers the same way.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum RatingScale {
+    /// One to five stars.
+    FiveStar,
+    /// A percentage from 0 to 100.
+    Percentage,
+    /// Thumbs down (`0`) or up (`1`).
+    Thumbs,
+    /// Any rating from `min` to `max` inclusive.
+    Range { min: u8, max: u8 },
+}
+
+impl RatingScale {
+    /// Returns the lowest and the highest rating on the scale.
+    pub fn bounds(&self) -> (u8, u8) {
+        match *self {
+            Self::FiveStar => (1, 5),
+            Self::Percentage => (0, 100),
+            Self::Thumbs => (0, 1),
+            Self::Range { min, max } => (min, max),
+        }
+    }
+
+    /// Returns whether the scale is well formed, i.e. its lowest rating is not above its highest.
+    pub fn is_valid(&self) -> bool {
+        let (min, max) = self.bounds();
+        min <= max
+    }
+
+    /// Returns whether `rating` lies on the scale.
+    pub fn contains(&self, rating: u8) -> bool {
+        let (min, max) = self.bounds();
+        (min..=max).contains(&rating)
+    }
+}
+
+/// The number of buckets in the histogram of a `RatingAggregate`.
+pub const HISTOGRAM_BUCKETS: usize = 10;
+
+/// Running statistics of the ratings given to a target on scales with the same bounds.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RatingAggregate {
+    /// The number of ratings.
+    pub count: u32,
+    /// The sum of the ratings.
+    pub sum: u64,
+    /// The sum of the squared ratings, from which the variance follows.
+    pub sum_of_squares: u64,
+    /// The number of ratings in each of `HISTOGRAM_BUCKETS` equal parts of the scale, lowest
+    /// first.
+    pub histogram: [u32; HISTOGRAM_BUCKETS],
+}
+
+impl RatingAggregate {
+    /// Returns the histogram bucket of `rating` on a scale from `min` to `max`.
+    pub fn bucket((min, max): (u8, u8), rating: u8) -> usize {
+        let span = usize::from(max.saturating_sub(min)) + 1;
+        (usize::from(rating.saturating_sub(min)) * HISTOGRAM_BUCKETS / span).min(HISTOGRAM_BUCKETS - 1)
+    }
+
+    /// Adds `rating`, given on a scale with `bounds`.
+    pub fn insert(&mut self, bounds: (u8, u8), rating: u8) {
+        let rating_u64 = u64::from(rating);
+        self.count = self.count.saturating_add(1);
+        self.sum = self.sum.saturating_add(rating_u64);
+        self.sum_of_squares = self.sum_of_squares.saturating_add(rating_u64 * rating_u64);
+        let bucket = &mut self.histogram[Self::bucket(bounds, rating)];
+        *bucket = bucket.saturating_add(1);
+    }
+
+    /// Takes out `rating`, given on a scale with `bounds`, which must have been added before.
+    pub fn remove(&mut self, bounds: (u8, u8), rating: u8) {
+        let rating_u64 = u64::from(rating);
+        self.count = self.count.saturating_sub(1);
+        self.sum = self.sum.saturating_sub(rating_u64);
+        self.sum_of_squares = self.sum_of_squares.saturating_sub(rating_u64 * rating_u64);
+        let bucket = &mut self.histogram[Self::bucket(bounds, rating)];
+        *bucket = bucket.saturating_sub(1);
+    }
+}
+
+/// A rating of a target together with the scale it was given on.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    CloneNoBound,
+    PartialEqNoBound,
+    EqNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxCategoryLength, MaxCommentHashLength))]
+#[codec(mel_bound())]
+pub struct RatingSignal<MaxCategoryLength: Get<u32>, MaxCommentHashLength: Get<u32>> {
+    /// The scale `rating` is given on.
+    pub scale: RatingScale,
+    /// The rating, which must lie on `scale`.
+    pub rating: u8,
+    /// The aspect of the target being rated, e.g. `accuracy`, if not the target as a whole.
+    pub category: Option<BoundedVec<u8, MaxCategoryLength>>,
+    /// Hash of an off-chain comment explaining the rating.
+    pub comment_hash: Option<BoundedVec<u8, MaxCommentHashLength>>,
+}
+
+/// A service identifier claimed by an account, together with the accounts allowed to send
+/// service signals for it.
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxPublishers))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+pub struct ServiceInfo<
+    AccountId: Clone + PartialEq + core::fmt::Debug,
+    Balance: Clone + PartialEq + core::fmt::Debug,
+    MaxPublishers: Get<u32>,
+> {
+    /// The account that registered the service, which may always publish for it.
+    pub owner: AccountId,
+    /// The deposit held from the owner for the registration.
+    pub deposit: Balance,
+    /// The accounts the owner authorized to publish for the service.
+    pub publishers: BoundedVec<AccountId, MaxPublishers>,
+}
+
+/// The deposit held for a noted signal payload.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PayloadTicket<AccountId, Balance> {
+    /// The account that noted the payload and may unnote it.
+    pub depositor: AccountId,
+    /// The deposit held from the depositor.
+    pub deposit: Balance,
+    /// The length of the payload in bytes.
+    pub len: u32,
+}
+
+/// The value of a signal parameter, typed so that applications reading it agree on how to
+/// interpret it.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum SignalParameterValue {
+    /// A flag.
+    Bool(bool),
+    /// A small unsigned number, as all parameters were before values were typed.
+    U8(u8),
+    /// An unsigned number.
+    U32(u32),
+    /// A large unsigned number.
+    U64(u64),
+    /// A signed number, e.g. a coefficient scaled by a factor the application agrees on.
+    I64(i64),
+}
+
+/// A signal parameter together with the block it was last set in.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SignalParameter<BlockNumber> {
+    /// The value of the parameter.
+    pub value: SignalParameterValue,
+    /// The block the parameter was last set in.
+    pub changed_at: BlockNumber,
+}
+
+/// The free signals an account sent in its current rate limit period.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SignalRate<BlockNumber> {
+    /// The block the period started in.
+    pub period_start: BlockNumber,
+    /// The number of free signals sent since `period_start`.
+    pub count: u32,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
         dispatch::DispatchResultWithPostInfo,
         pallet_prelude::*,
-        traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
+        traits::{
+            fungible::{Inspect, Mutate, MutateHold},
+            tokens::Precision,
+        },
+        weights::WeightMeter,
     };
+    use sp_runtime::{traits::Hash, Saturating};
     use frame_system::pallet_prelude::*;
 
-    use crate::weights::WeightInfo;
+    use alloc::vec::Vec;
+
+    use crate::{
+        weights::WeightInfo, whiteflag, PayloadTicket, RatingAggregate, RatingSignal, ServiceInfo, SignalParameter,
+        SignalParameterValue, SignalRate,
+    };
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    pub type RatingSignalOf<T> =
+        RatingSignal<<T as Config>::MaxCategoryLength, <T as Config>::MaxCommentHashLength>;
+
+    pub type ServiceInfoOf<T> = ServiceInfo<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T>,
+        <T as Config>::MaxServicePublishers,
+    >;
+
+    pub type PayloadTicketOf<T> = PayloadTicket<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
 
-    type BalanceOf<T> =
-        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type SignalParameterOf<T> = SignalParameter<BlockNumberFor<T>>;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -35,62 +247,192 @@ pub mod pallet {
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
         /// Accesses the chain's native currency for this pallet.
-        type Currency: LockableCurrency<
-            Self::AccountId,
-            Moment = frame_system::pallet_prelude::BlockNumberFor<Self>,
-        >;
+        type Currency: Mutate<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
         /// The maximum size of a signal.
         type MaxSize: Get<u32>;
-        /// The identifier for the lock used to store signal deposits.
-        type LockId: Get<LockIdentifier>;
-        /// The price of a signal lock.
+        /// The deposit held for each active rating signal.
         type LockPrice: Get<u32>;
+        /// The maximum length of a rating category.
+        type MaxCategoryLength: Get<u32>;
+        /// The maximum length of a rating's comment hash.
+        type MaxCommentHashLength: Get<u32>;
+        /// The deposit held for each registered service.
+        type ServiceDeposit: Get<u32>;
+        /// The maximum number of publishers a service owner can authorize.
+        type MaxServicePublishers: Get<u32>;
+        /// The maximum size of a payload noted for payload signals.
+        type MaxPayloadSize: Get<u32>;
+        /// The deposit held for each noted payload, regardless of its size.
+        type PayloadDepositBase: Get<u32>;
+        /// The deposit held for each byte of a noted payload.
+        type PayloadDepositPerByte: Get<u32>;
+        /// The maximum number of free signals an account may send per `SignalRatePeriod`, as
+        /// enforced by the `CheckSignalRate` transaction extension.
+        type MaxSignalsPerPeriod: Get<u32>;
+        /// The number of blocks in a rate limit period.
+        type SignalRatePeriod: Get<BlockNumberFor<Self>>;
+        /// The maximum number of signal parameters an account can set.
+        type MaxSignalParameters: Get<u32>;
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// Reasons for which this pallet places a hold on an account's funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds are held as the deposit for an active rating signal.
+        RatingDeposit,
+        /// Funds are held as the deposit for a registered service.
+        ServiceDeposit,
+        /// Funds are held as the deposit for a noted signal payload.
+        PayloadDeposit,
+    }
+
     #[pallet::storage]
     #[pallet::getter(fn rating_signal_list)]
-    /// Maps identity numbers to a signal transaction hash and a rating number.
+    /// Maps identity numbers to a signal transaction hash and a rating given on a declared scale.
     pub type RatingSignalList<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         T::AccountId,
         Blake2_128Concat,
         BoundedVec<u8, T::MaxSize>,
-        u8,
-        ValueQuery,
+        RatingSignalOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn rating_deposits)]
+    /// Maps identity numbers and rated targets to the deposit held for the rating.
+    pub type RatingDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxSize>,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn rating_aggregates)]
+    /// Aggregates the ratings of each target, keyed by target and then by the bounds of the
+    /// scales the ratings were given on.
+    pub type RatingAggregates<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxSize>,
+        Blake2_128Concat,
+        (u8, u8),
+        RatingAggregate,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn services)]
+    /// Maps registered service identifiers to their owner and authorized publishers.
+    pub type Services<T: Config> =
+        StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxSize>, ServiceInfoOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn payload_ticket)]
+    /// Maps the hashes of noted payloads to the deposit held for them.
+    pub type PayloadTickets<T: Config> = StorageMap<_, Identity, T::Hash, PayloadTicketOf<T>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn payload)]
+    /// Maps the hashes of noted payloads to the payloads themselves.
+    pub type Payloads<T: Config> =
+        StorageMap<_, Identity, T::Hash, BoundedVec<u8, T::MaxPayloadSize>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn signal_rates)]
+    /// Counts the free signals each account sent in its current rate limit period.
+    pub type SignalRates<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SignalRate<BlockNumberFor<T>>, OptionQuery>;
+
+    #[pallet::storage]
+    /// Schedules the rate limit periods in `SignalRates` to be pruned, keyed by the block at
+    /// which they end and then by account.
+    pub type SignalRateExpiry<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
     >;
 
+    #[pallet::storage]
+    /// The first block of `SignalRateExpiry` that has not been swept completely.
+    pub type SignalRateCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn signal_paramter_list)]
-    /// Maps identity numbers to a signal transaction hash and a rating number.
+    /// Maps identity numbers and parameter names to the parameter's value and the block it was
+    /// last set in.
     pub type SignalParameterList<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         T::AccountId,
         Blake2_128Concat,
         BoundedVec<u8, T::MaxSize>,
-        u8,
-        ValueQuery,
+        SignalParameterOf<T>,
+        OptionQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn signal_parameter_counts)]
+    /// Counts the signal parameters each account has set.
+    pub type SignalParameterCounts<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Indicates that a signal parameter has been set.
-        SignalParameterSet { who: T::AccountId },
-        /// Indicates that a signal lock has been created.
+        SignalParameterSet { who: T::AccountId, name: BoundedVec<u8, T::MaxSize>, value: SignalParameterValue },
+        /// Indicates that a signal parameter has been removed.
+        SignalParameterRemoved { who: T::AccountId, name: BoundedVec<u8, T::MaxSize> },
+        /// Indicates that a rating deposit has been placed on hold.
         SignalLock { account: <T as frame_system::Config>::AccountId, amount: BalanceOf<T> },
-        /// Indicates that a signal lock has been extended.
-        SignalLockExtended { account: <T as frame_system::Config>::AccountId, amount: BalanceOf<T> },
-        /// Indicates that a signal lock has been removed.
-        SignalUnlock { account: <T as frame_system::Config>::AccountId },
+        /// Indicates that a rating deposit has been released.
+        SignalUnlock { account: <T as frame_system::Config>::AccountId, amount: BalanceOf<T> },
         /// Represents a signal sent by an identity.
         SignalSent { signal: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
         /// Represents a signal sent by an identity for a particular application or service.
         ServiceSignalSent { service_identifier: BoundedVec<u8, T::MaxSize>, url: BoundedVec<u8, T::MaxSize>, who: T::AccountId },
+        /// Represents a validated Whiteflag message sent by an identity.
+        WhiteflagSignalSent {
+            who: T::AccountId,
+            message_code: whiteflag::MessageCode,
+            duress: bool,
+            reference_indicator: u8,
+            referenced_message: [u8; 32],
+            message: BoundedVec<u8, T::MaxSize>,
+        },
+        /// Indicates that an identity registered a service identifier.
+        ServiceRegistered { service_identifier: BoundedVec<u8, T::MaxSize>, owner: T::AccountId },
+        /// Indicates that a service owner gave up a service identifier.
+        ServiceUnregistered { service_identifier: BoundedVec<u8, T::MaxSize>, owner: T::AccountId },
+        /// Indicates that a service owner authorized an account to publish for the service.
+        ServicePublisherAuthorized { service_identifier: BoundedVec<u8, T::MaxSize>, publisher: T::AccountId },
+        /// Indicates that a service owner withdrew an account's authorization to publish.
+        ServicePublisherRevoked { service_identifier: BoundedVec<u8, T::MaxSize>, publisher: T::AccountId },
+        /// Indicates that an identity noted a payload for payload signals.
+        PayloadNoted { hash: T::Hash, who: T::AccountId, deposit: BalanceOf<T> },
+        /// Indicates that a payload was removed and its deposit released.
+        PayloadUnnoted { hash: T::Hash, who: T::AccountId },
+        /// Represents a signal sent by an identity whose content is the noted payload with `hash`.
+        PayloadSignalSent { hash: T::Hash, who: T::AccountId },
         /// Indicates that an identity issued a new rating signal.
         RatingSignalSent { who: T::AccountId },
         /// Indicates that an identity updated a rating signal.
@@ -107,89 +449,137 @@ pub mod pallet {
         RatingSignalAlreadyExists,
         /// Requested rating signal does not exist.
         RatingSignalDoesNotExist,
+        /// The rating does not lie on its scale.
+        RatingOutOfScale,
+        /// The payload is not a valid unencrypted Whiteflag message.
+        InvalidWhiteflagMessage,
+        /// The service identifier has already been registered.
+        ServiceAlreadyRegistered,
+        /// The service identifier has not been registered.
+        ServiceNotRegistered,
+        /// Only the owner of the service may do this.
+        NotServiceOwner,
+        /// The sender may not publish signals for the registered service.
+        NotServicePublisher,
+        /// The account is already authorized to publish for the service.
+        PublisherAlreadyAuthorized,
+        /// The account is not authorized to publish for the service.
+        PublisherNotAuthorized,
+        /// The service already has the maximum number of publishers.
+        TooManyPublishers,
+        /// A payload with the same hash has already been noted.
+        PayloadAlreadyNoted,
+        /// No payload with the hash has been noted.
+        PayloadNotNoted,
+        /// Only the account that noted the payload may unnote it.
+        NotPayloadDepositor,
+        /// Signal parameters must have a name.
+        EmptySignalParameterName,
+        /// The account has set the maximum number of signal parameters.
+        TooManySignalParameters,
+        /// The account has not set the signal parameter.
+        SignalParameterNotSet,
+        /// The lowest rating of the scale is above its highest.
+        InvalidRatingScale,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_signal_rates(now, remaining_weight)
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Defines coefficients that participants should use to weight rating functions.
+        /// Defines coefficients that participants should use to weight rating functions. Each
+        /// account may set up to `MaxSignalParameters` of its own parameters.
         #[pallet::weight(T::WeightInfo::set_signal_parameter())]
         #[pallet::call_index(0)]
         pub fn set_signal_parameter(
             origin: OriginFor<T>,
             name: BoundedVec<u8, T::MaxSize>,
-            value: u8,
+            value: SignalParameterValue,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            <SignalParameterList<T>>::insert(who.clone(), name.clone(), value);
-            Self::deposit_event(Event::SignalParameterSet { who });
+            ensure!(!name.is_empty(), Error::<T>::EmptySignalParameterName);
+            if !<SignalParameterList<T>>::contains_key(&who, &name) {
+                let count = <SignalParameterCounts<T>>::get(&who);
+                ensure!(count < T::MaxSignalParameters::get(), Error::<T>::TooManySignalParameters);
+                <SignalParameterCounts<T>>::insert(&who, count + 1);
+            }
+            let changed_at = frame_system::Pallet::<T>::block_number();
+            <SignalParameterList<T>>::insert(&who, &name, SignalParameter { value, changed_at });
+            Self::deposit_event(Event::SignalParameterSet { who, name, value });
             Ok(().into())
         }
 
-        /// Creates an on-chain event with a transaction hash as a pointer and a u8 as a rating number.
+        /// Removes a signal parameter of the origin.
+        #[pallet::weight(T::WeightInfo::remove_signal_parameter())]
+        #[pallet::call_index(17)]
+        pub fn remove_signal_parameter(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(<SignalParameterList<T>>::contains_key(&who, &name), Error::<T>::SignalParameterNotSet);
+            <SignalParameterList<T>>::remove(&who, &name);
+            <SignalParameterCounts<T>>::mutate_exists(&who, |count| {
+                *count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+            });
+            Self::deposit_event(Event::SignalParameterRemoved { who, name });
+            Ok(().into())
+        }
+
+        /// Creates an on-chain event with a transaction hash as a pointer and a rating given on a
+        /// declared scale, optionally for a category of the target and with a comment hash.
         #[pallet::weight(T::WeightInfo::send_rating_signal())]
         #[pallet::call_index(1)]
         pub fn send_rating_signal(
             origin: OriginFor<T>,
             target: BoundedVec<u8, T::MaxSize>,
-            rating: u8,
+            rating: RatingSignalOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            ensure!(rating.scale.is_valid(), Error::<T>::InvalidRatingScale);
+            ensure!(rating.scale.contains(rating.rating), Error::<T>::RatingOutOfScale);
             ensure!(
                 !<RatingSignalList<T>>::contains_key(who.clone(), target.clone()),
                 Error::<T>::RatingSignalAlreadyExists
             );
-            ensure!(
-                (T::Currency::free_balance(&who) > T::LockPrice::get().into()),
-                Error::<T>::InsufficientBalance
-            );
-            T::Currency::ensure_can_withdraw(
-                &who,
-                T::LockPrice::get().into(),
-                WithdrawReasons::all(),
-                <T as Config>::Currency::free_balance(&who),
-            )?;
+            let deposit: BalanceOf<T> = T::LockPrice::get().into();
+            T::Currency::hold(&HoldReason::RatingDeposit.into(), &who, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            <RatingDeposits<T>>::insert(who.clone(), target.clone(), deposit);
+            Self::aggregate_rating(&target, &rating, RatingAggregate::insert);
             <RatingSignalList<T>>::insert(who.clone(), target.clone(), rating);
-            T::Currency::set_lock(
-                T::LockId::get(),
-                &who,
-                T::LockPrice::get().into(),
-                WithdrawReasons::all(),
-            );
-            Self::deposit_event(Event::SignalLock { account: who.clone(), amount: T::LockPrice::get().into() });
+            Self::deposit_event(Event::SignalLock { account: who.clone(), amount: deposit });
             Self::deposit_event(Event::RatingSignalSent { who });
             Ok(().into())
         }
 
-        /// Updates an existing rating signal.
+        /// Updates an existing rating signal, which may move to another scale. The deposit held for
+        /// the rating is kept.
         #[pallet::weight(T::WeightInfo::update_rating_signal())]
         #[pallet::call_index(4)]
         pub fn update_rating_signal(
             origin: OriginFor<T>,
             target: BoundedVec<u8, T::MaxSize>,
-            new_rating: u8,
+            new_rating: RatingSignalOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            ensure!(
-                (T::Currency::free_balance(&who) > T::LockPrice::get().into()),
-                Error::<T>::InsufficientBalance
-            );
-            ensure!(
-                <RatingSignalList<T>>::contains_key(who.clone(), target.clone()),
-                Error::<T>::RatingSignalDoesNotExist
-            );
+            ensure!(new_rating.scale.is_valid(), Error::<T>::InvalidRatingScale);
+            ensure!(new_rating.scale.contains(new_rating.rating), Error::<T>::RatingOutOfScale);
+            let old_rating =
+                <RatingSignalList<T>>::get(who.clone(), target.clone()).ok_or(Error::<T>::RatingSignalDoesNotExist)?;
+            Self::aggregate_rating(&target, &old_rating, RatingAggregate::remove);
+            Self::aggregate_rating(&target, &new_rating, RatingAggregate::insert);
             <RatingSignalList<T>>::insert(who.clone(), target.clone(), new_rating);
-            T::Currency::extend_lock(
-                T::LockId::get(),
-                &who,
-                T::LockPrice::get().into(),
-                WithdrawReasons::all(),
-            );
-            Self::deposit_event(Event::SignalLockExtended { account: who.clone(), amount: T::LockPrice::get().into() });
             Self::deposit_event(Event::RatingSignalUpdated { who });
             Ok(().into())
         }
 
-        /// Puts out a signal cancelling a previous rating.
+        /// Puts out a signal cancelling a previous rating, releasing the deposit held for it.
         #[pallet::weight(T::WeightInfo::revoke_rating_signal())]
         #[pallet::call_index(5)]
         pub fn revoke_rating_signal(
@@ -197,13 +587,12 @@ pub mod pallet {
             target: BoundedVec<u8, T::MaxSize>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            ensure!(
-                <RatingSignalList<T>>::contains_key(who.clone(), target.clone()),
-                Error::<T>::RatingSignalDoesNotExist
-            );
-            <RatingSignalList<T>>::remove(who.clone(), target.clone());
-            T::Currency::remove_lock(T::LockId::get(), &who);
-            Self::deposit_event(Event::SignalUnlock { account: who.clone() });
+            let rating =
+                <RatingSignalList<T>>::take(who.clone(), target.clone()).ok_or(Error::<T>::RatingSignalDoesNotExist)?;
+            Self::aggregate_rating(&target, &rating, RatingAggregate::remove);
+            let deposit = <RatingDeposits<T>>::take(who.clone(), target.clone()).unwrap_or_default();
+            let amount = T::Currency::release(&HoldReason::RatingDeposit.into(), &who, deposit, Precision::BestEffort)?;
+            Self::deposit_event(Event::SignalUnlock { account: who.clone(), amount });
             Self::deposit_event(Event::RatingSignalRevoked { who });
             Ok(().into())
         }
@@ -226,7 +615,8 @@ pub mod pallet {
         /// Sends a hexadecimal signal tagged for a particular application or service using Fennel
         /// Protocol. This is intended for specific signal-consuming applications like the Whiteflag
         /// Protocol to issue events to the chain that can be immediately identified with their
-        /// application.
+        /// application. Signals for a registered service may only be sent by its owner and the
+        /// publishers it authorized.
         #[pallet::weight(T::WeightInfo::send_service_signal())]
         #[pallet::call_index(8)]
         pub fn send_service_signal(
@@ -235,8 +625,284 @@ pub mod pallet {
             url: BoundedVec<u8, T::MaxSize>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            if let Some(service) = <Services<T>>::get(&service_identifier) {
+                ensure!(
+                    service.owner == who || service.publishers.contains(&who),
+                    Error::<T>::NotServicePublisher
+                );
+            }
             Self::deposit_event(Event::ServiceSignalSent { service_identifier, url, who });
             Ok(().into())
         }
+
+        /// Sends a binary encoded Whiteflag message, which is decoded and validated on-chain so
+        /// that consumers can rely on its header without decoding it themselves. Encrypted
+        /// messages cannot be validated and are rejected.
+        #[pallet::weight(T::WeightInfo::send_whiteflag_signal())]
+        #[pallet::call_index(9)]
+        pub fn send_whiteflag_signal(
+            origin: OriginFor<T>,
+            message: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let decoded =
+                whiteflag::Message::from_bytes(&message).map_err(|_| Error::<T>::InvalidWhiteflagMessage)?;
+            Self::deposit_event(Event::WhiteflagSignalSent {
+                who,
+                message_code: decoded.header.message_code,
+                duress: decoded.header.duress,
+                reference_indicator: decoded.header.reference_indicator,
+                referenced_message: decoded.header.referenced_message,
+                message,
+            });
+            Ok(().into())
+        }
+
+        /// Claims a service identifier for the origin, holding `ServiceDeposit` until the service
+        /// is unregistered. Only the owner and the publishers it authorizes may then send service
+        /// signals for the identifier.
+        #[pallet::weight(T::WeightInfo::register_service())]
+        #[pallet::call_index(10)]
+        pub fn register_service(
+            origin: OriginFor<T>,
+            service_identifier: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(!<Services<T>>::contains_key(&service_identifier), Error::<T>::ServiceAlreadyRegistered);
+            let deposit: BalanceOf<T> = T::ServiceDeposit::get().into();
+            T::Currency::hold(&HoldReason::ServiceDeposit.into(), &who, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            <Services<T>>::insert(
+                &service_identifier,
+                ServiceInfo { owner: who.clone(), deposit, publishers: BoundedVec::new() },
+            );
+            Self::deposit_event(Event::ServiceRegistered { service_identifier, owner: who });
+            Ok(().into())
+        }
+
+        /// Gives up a service identifier owned by the origin, releasing its deposit. Anyone may
+        /// send service signals for the identifier again until it is registered anew.
+        #[pallet::weight(T::WeightInfo::unregister_service())]
+        #[pallet::call_index(11)]
+        pub fn unregister_service(
+            origin: OriginFor<T>,
+            service_identifier: BoundedVec<u8, T::MaxSize>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let service = <Services<T>>::get(&service_identifier).ok_or(Error::<T>::ServiceNotRegistered)?;
+            ensure!(service.owner == who, Error::<T>::NotServiceOwner);
+            <Services<T>>::remove(&service_identifier);
+            T::Currency::release(&HoldReason::ServiceDeposit.into(), &who, service.deposit, Precision::BestEffort)?;
+            Self::deposit_event(Event::ServiceUnregistered { service_identifier, owner: who });
+            Ok(().into())
+        }
+
+        /// Authorizes `publisher` to send service signals for a service owned by the origin.
+        #[pallet::weight(T::WeightInfo::authorize_service_publisher())]
+        #[pallet::call_index(12)]
+        pub fn authorize_service_publisher(
+            origin: OriginFor<T>,
+            service_identifier: BoundedVec<u8, T::MaxSize>,
+            publisher: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            <Services<T>>::try_mutate(&service_identifier, |service| -> DispatchResult {
+                let service = service.as_mut().ok_or(Error::<T>::ServiceNotRegistered)?;
+                ensure!(service.owner == who, Error::<T>::NotServiceOwner);
+                ensure!(!service.publishers.contains(&publisher), Error::<T>::PublisherAlreadyAuthorized);
+                service.publishers.try_push(publisher.clone()).map_err(|_| Error::<T>::TooManyPublishers)?;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::ServicePublisherAuthorized { service_identifier, publisher });
+            Ok(().into())
+        }
+
+        /// Withdraws the authorization of `publisher` to send service signals for a service owned
+        /// by the origin.
+        #[pallet::weight(T::WeightInfo::revoke_service_publisher())]
+        #[pallet::call_index(13)]
+        pub fn revoke_service_publisher(
+            origin: OriginFor<T>,
+            service_identifier: BoundedVec<u8, T::MaxSize>,
+            publisher: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            <Services<T>>::try_mutate(&service_identifier, |service| -> DispatchResult {
+                let service = service.as_mut().ok_or(Error::<T>::ServiceNotRegistered)?;
+                ensure!(service.owner == who, Error::<T>::NotServiceOwner);
+                let position = service
+                    .publishers
+                    .iter()
+                    .position(|authorized| *authorized == publisher)
+                    .ok_or(Error::<T>::PublisherNotAuthorized)?;
+                service.publishers.remove(position);
+                Ok(())
+            })?;
+            Self::deposit_event(Event::ServicePublisherRevoked { service_identifier, publisher });
+            Ok(().into())
+        }
+
+        /// Stores a payload too large for `send_signal` under its hash, holding a deposit of
+        /// `PayloadDepositBase` plus `PayloadDepositPerByte` for each byte until it is unnoted.
+        #[pallet::weight(T::WeightInfo::note_payload(payload.len() as u32))]
+        #[pallet::call_index(14)]
+        pub fn note_payload(
+            origin: OriginFor<T>,
+            payload: BoundedVec<u8, T::MaxPayloadSize>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let hash = T::Hashing::hash(&payload);
+            ensure!(!<PayloadTickets<T>>::contains_key(hash), Error::<T>::PayloadAlreadyNoted);
+            let len = payload.len() as u32;
+            let deposit = Self::payload_deposit(len);
+            T::Currency::hold(&HoldReason::PayloadDeposit.into(), &who, deposit)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            <PayloadTickets<T>>::insert(hash, PayloadTicket { depositor: who.clone(), deposit, len });
+            <Payloads<T>>::insert(hash, payload);
+            Self::deposit_event(Event::PayloadNoted { hash, who, deposit });
+            Ok(().into())
+        }
+
+        /// Removes a payload noted by the origin and releases its deposit. Signals that referenced
+        /// the payload keep its hash, but the payload can no longer be retrieved on-chain.
+        #[pallet::weight(T::WeightInfo::unnote_payload())]
+        #[pallet::call_index(15)]
+        pub fn unnote_payload(origin: OriginFor<T>, hash: T::Hash) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let ticket = <PayloadTickets<T>>::get(hash).ok_or(Error::<T>::PayloadNotNoted)?;
+            ensure!(ticket.depositor == who, Error::<T>::NotPayloadDepositor);
+            <PayloadTickets<T>>::remove(hash);
+            <Payloads<T>>::remove(hash);
+            T::Currency::release(&HoldReason::PayloadDeposit.into(), &who, ticket.deposit, Precision::BestEffort)?;
+            Self::deposit_event(Event::PayloadUnnoted { hash, who });
+            Ok(().into())
+        }
+
+        /// Sends a signal whose content is a payload noted with `note_payload`, so that signals
+        /// larger than `MaxSize` can be sent and are addressed by the hash of their content.
+        #[pallet::weight(T::WeightInfo::send_payload_signal())]
+        #[pallet::call_index(16)]
+        pub fn send_payload_signal(origin: OriginFor<T>, hash: T::Hash) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(<PayloadTickets<T>>::contains_key(hash), Error::<T>::PayloadNotNoted);
+            Self::deposit_event(Event::PayloadSignalSent { hash, who });
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Returns whether `call` is a free signal, which only deposits an event and is therefore
+        /// rate limited.
+        pub fn is_free_signal(call: &Call<T>) -> bool {
+            matches!(
+                call,
+                Call::send_signal { .. }
+                    | Call::send_service_signal { .. }
+                    | Call::send_whiteflag_signal { .. }
+                    | Call::send_payload_signal { .. }
+            )
+        }
+
+        /// Returns the free signals `who` sent in the rate limit period `now` lies in. A new
+        /// period starts at `now` once the last one has passed.
+        pub fn signal_rate(who: &T::AccountId, now: BlockNumberFor<T>) -> SignalRate<BlockNumberFor<T>> {
+            match <SignalRates<T>>::get(who) {
+                Some(rate) if now < rate.period_start.saturating_add(T::SignalRatePeriod::get()) => rate,
+                _ => SignalRate { period_start: now, count: 0 },
+            }
+        }
+
+        /// Counts one more free signal from `who`, whose rate before the signal is `rate`. A
+        /// period that starts with this signal is scheduled to be pruned once it ends.
+        pub(crate) fn record_free_signal(who: &T::AccountId, mut rate: SignalRate<BlockNumberFor<T>>) {
+            if rate.count == 0 {
+                if !<SignalRateCursor<T>>::exists() {
+                    <SignalRateCursor<T>>::put(rate.period_start);
+                }
+                let end = rate.period_start.saturating_add(T::SignalRatePeriod::get());
+                <SignalRateExpiry<T>>::insert(end, who, ());
+            }
+            rate.count = rate.count.saturating_add(1);
+            <SignalRates<T>>::insert(who, rate);
+        }
+
+        /// Prunes the rate limit periods scheduled to end up to `now`, spending at most `limit`.
+        /// Whatever does not fit is left for the next call.
+        pub(crate) fn prune_signal_rates(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(limit);
+            if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+                return meter.consumed();
+            }
+            let Some(mut block) = <SignalRateCursor<T>>::get() else {
+                return meter.consumed();
+            };
+            while block <= now {
+                match <SignalRateExpiry<T>>::iter_key_prefix(block).next() {
+                    Some(who) => {
+                        if meter.try_consume(T::WeightInfo::prune_signal_rate()).is_err() {
+                            break;
+                        }
+                        Self::prune_signal_rate(block, &who, now);
+                    },
+                    None => {
+                        if meter.try_consume(T::DbWeight::get().reads(1)).is_err() {
+                            break;
+                        }
+                        block.saturating_inc();
+                    },
+                }
+            }
+            <SignalRateCursor<T>>::put(block);
+            meter.consumed()
+        }
+
+        /// Removes the rate limit period of `who` scheduled to end at `block`, unless `who` has
+        /// started a later period by `now`.
+        pub(crate) fn prune_signal_rate(block: BlockNumberFor<T>, who: &T::AccountId, now: BlockNumberFor<T>) {
+            <SignalRateExpiry<T>>::remove(block, who);
+            <SignalRates<T>>::mutate_exists(who, |rate| {
+                if rate.as_ref().is_some_and(|rate| rate.period_start.saturating_add(T::SignalRatePeriod::get()) <= now) {
+                    *rate = None;
+                }
+            });
+        }
+
+        /// Returns the deposit held for a payload of `len` bytes.
+        pub fn payload_deposit(len: u32) -> BalanceOf<T> {
+            let per_byte: BalanceOf<T> = T::PayloadDepositPerByte::get().into();
+            let base: BalanceOf<T> = T::PayloadDepositBase::get().into();
+            base.saturating_add(per_byte.saturating_mul(len.into()))
+        }
+
+        /// Returns the signal parameters `who` has set, ordered by name.
+        pub fn signal_parameters_of(who: &T::AccountId) -> Vec<(Vec<u8>, SignalParameterOf<T>)> {
+            let mut parameters: Vec<_> = <SignalParameterList<T>>::iter_prefix(who)
+                .map(|(name, parameter)| (name.into_inner(), parameter))
+                .collect();
+            parameters.sort_by(|(a, _), (b, _)| a.cmp(b));
+            parameters
+        }
+
+        /// Returns the aggregated ratings of `target` for each bounds of scale it was rated on.
+        pub fn rating_aggregates_of(target: &BoundedVec<u8, T::MaxSize>) -> Vec<((u8, u8), RatingAggregate)> {
+            <RatingAggregates<T>>::iter_prefix(target).collect()
+        }
+
+        /// Applies `change` with `rating` to the aggregate of `target` for the bounds of the
+        /// rating's scale, removing the aggregate once it holds no ratings.
+        pub(crate) fn aggregate_rating(
+            target: &BoundedVec<u8, T::MaxSize>,
+            rating: &RatingSignalOf<T>,
+            change: fn(&mut RatingAggregate, (u8, u8), u8),
+        ) {
+            let bounds = rating.scale.bounds();
+            <RatingAggregates<T>>::mutate_exists(target, bounds, |aggregate| {
+                let mut updated = aggregate.take().unwrap_or_default();
+                change(&mut updated, bounds, rating.rating);
+                if updated.count > 0 {
+                    *aggregate = Some(updated);
+                }
+            });
+        }
     }
 }
@@ -0,0 +1,111 @@
+//! Storage migrations for the signal pallet.
+
+/// Migrates rating deposits from the lock shared by all ratings of an account to a hold per
+/// rating.
+pub mod v1 {
+    use alloc::collections::BTreeSet;
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        traits::{fungible::MutateHold, LockIdentifier, LockableCurrency, UncheckedOnRuntimeUpgrade},
+    };
+    use sp_runtime::traits::Zero;
+
+    use crate::{
+        pallet::{BalanceOf, RatingSignalOf},
+        Config, HoldReason, Pallet, RatingAggregate, RatingDeposits, RatingScale, RatingSignal, RatingSignalList,
+    };
+
+    /// Gives every rating stored as a bare `u8` the scale `Range { min: 0, max: 255 }` and
+    /// counts it in its target's aggregate. Then removes the `LockId` lock of every account that
+    /// rated and holds `LockPrice` for each of its ratings instead. A rating whose deposit cannot
+    /// be held is kept with a deposit of zero.
+    pub struct UncheckedMigrateToV1<T, OldCurrency, LockId>(PhantomData<(T, OldCurrency, LockId)>);
+
+    impl<T, OldCurrency, LockId> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T, OldCurrency, LockId>
+    where
+        T: Config,
+        OldCurrency: LockableCurrency<T::AccountId>,
+        LockId: Get<LockIdentifier>,
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let mut accounts = BTreeSet::new();
+            let mut ratings = 0u64;
+            <RatingSignalList<T>>::translate::<u8, _>(|who, target, rating| {
+                let rating: RatingSignalOf<T> = RatingSignal {
+                    scale: RatingScale::Range { min: 0, max: u8::MAX },
+                    rating,
+                    category: None,
+                    comment_hash: None,
+                };
+                Pallet::<T>::aggregate_rating(&target, &rating, RatingAggregate::insert);
+                accounts.insert(who);
+                ratings += 1;
+                Some(rating)
+            });
+            for who in accounts.iter() {
+                OldCurrency::remove_lock(LockId::get(), who);
+                for target in <RatingSignalList<T>>::iter_key_prefix(who) {
+                    let deposit: BalanceOf<T> = T::LockPrice::get().into();
+                    let deposit = match T::Currency::hold(&HoldReason::RatingDeposit.into(), who, deposit) {
+                        Ok(()) => deposit,
+                        Err(_) => Zero::zero(),
+                    };
+                    <RatingDeposits<T>>::insert(who, target, deposit);
+                }
+            }
+            let accounts = accounts.len() as u64;
+            // Each rating is translated and aggregated, and its deposit held and recorded; each
+            // account has its lock removed.
+            T::DbWeight::get().reads_writes(ratings * 4 + accounts * 2, ratings * 5 + accounts * 2)
+        }
+    }
+
+    /// Runs `UncheckedMigrateToV1` if the pallet's storage version is 0, then sets it to 1.
+    pub type MigrateToV1<T, OldCurrency, LockId> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateToV1<T, OldCurrency, LockId>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migrates signal parameters from bare `u8` values to typed values that record the block they
+/// were last set in.
+pub mod v2 {
+    use core::marker::PhantomData;
+    use frame_support::{migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade};
+
+    use crate::{Config, Pallet, SignalParameter, SignalParameterCounts, SignalParameterList, SignalParameterValue};
+
+    /// Turns every signal parameter stored as a bare `u8` into a `SignalParameterValue::U8` last
+    /// changed in the block of the upgrade, which is the earliest block its value is known for,
+    /// and counts the parameters of each account. Accounts keep parameters beyond
+    /// `MaxSignalParameters`, but cannot add more until they removed enough of them.
+    pub struct UncheckedMigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let changed_at = frame_system::Pallet::<T>::block_number();
+            let mut parameters = 0u64;
+            <SignalParameterList<T>>::translate::<u8, _>(|who, _name, value| {
+                <SignalParameterCounts<T>>::mutate(who, |count| *count = count.saturating_add(1));
+                parameters += 1;
+                Some(SignalParameter { value: SignalParameterValue::U8(value), changed_at })
+            });
+            // Each parameter is translated and counted.
+            T::DbWeight::get().reads_writes(parameters * 2 + 1, parameters * 2)
+        }
+    }
+
+    /// Runs `UncheckedMigrateToV2` if the pallet's storage version is 1, then sets it to 2.
+    pub type MigrateToV2<T> = VersionedMigration<
+        1,
+        2,
+        UncheckedMigrateToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,6 +1,6 @@
 use crate as pallet_signal;
 use frame_support::derive_impl;
-use sp_core::{ConstU32, ConstU128};
+use sp_core::{ConstU32, ConstU64, ConstU128};
 use sp_runtime::BuildStorage;
 
 pub type Balance = u128;
@@ -48,12 +48,12 @@ impl pallet_balances::Config for Test {
     type WeightInfo = ();
     type FreezeIdentifier = [u8; 8];
     type MaxFreezes = ();
-    type RuntimeHoldReason = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
     type RuntimeFreezeReason = ();
     type DoneSlashHandler = ();
 }
 
-// Define a LockId type for the signal pallet
+// The lock rating deposits were stored under before they were held
 pub struct SignalLockId;
 impl frame_support::traits::Get<[u8; 8]> for SignalLockId {
     fn get() -> [u8; 8] {
@@ -65,9 +65,19 @@ impl pallet_signal::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type MaxSize = ConstU32<1024>;
-    type LockId = SignalLockId;
     type LockPrice = ConstU32<10>;
+    type MaxCategoryLength = ConstU32<32>;
+    type MaxCommentHashLength = ConstU32<68>;
+    type ServiceDeposit = ConstU32<25>;
+    type MaxServicePublishers = ConstU32<3>;
+    type MaxPayloadSize = ConstU32<4096>;
+    type PayloadDepositBase = ConstU32<5>;
+    type PayloadDepositPerByte = ConstU32<1>;
+    type MaxSignalsPerPeriod = ConstU32<2>;
+    type SignalRatePeriod = ConstU64<10>;
+    type MaxSignalParameters = ConstU32<3>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -1,31 +1,148 @@
-use crate::{mock::*, Error, Event, RatingSignalList, SignalParameterList};
+use crate::{mock::*, migrations, pallet::RatingSignalOf, Error, Event, HoldReason, RatingDeposits, RatingAggregate, RatingAggregates, RatingScale, RatingSignal, RatingSignalList, CheckSignalRate, SIGNAL_RATE_LIMITED, SignalRateExpiry, SignalRates, Payloads, PayloadTickets, Services, SignalParameter, SignalParameterCounts, SignalParameterList, SignalParameterValue, whiteflag};
 use frame_support::{assert_ok, assert_noop};
 use sp_core::ConstU32;
 use frame_support::BoundedVec;
-use frame_support::traits::Currency;
+use frame_support::traits::{
+    fungible::InspectHold, Currency, Get, GetStorageVersion, Hooks, LockableCurrency, OnRuntimeUpgrade,
+    StorageVersion, WithdrawReasons,
+};
+use frame_support::{dispatch::GetDispatchInfo, weights::Weight};
+use sp_runtime::{
+    traits::{DispatchTransaction, Dispatchable, Hash},
+    transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+};
 
 #[test]
 fn set_signal_parameter_works_and_emits_event() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         let name = BoundedVec::<u8, ConstU32<1024>>::try_from(b"PARAM".to_vec()).unwrap();
-        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name.clone(), 42));
+        let value = SignalParameterValue::U8(42);
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name.clone(), value));
         // Storage check
-        assert_eq!(SignalParameterList::<Test>::get(1, &name), 42);
+        assert_eq!(SignalParameterList::<Test>::get(1, &name), Some(SignalParameter { value, changed_at: 1 }));
+        assert_eq!(SignalParameterCounts::<Test>::get(1), 1);
         // Event check
-        System::assert_last_event(Event::SignalParameterSet { who: 1 }.into());
+        System::assert_last_event(Event::SignalParameterSet { who: 1, name, value }.into());
     });
 }
 
+#[test]
+fn changing_a_signal_parameter_records_its_value_and_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let name = BoundedVec::<u8, ConstU32<1024>>::try_from(b"WEIGHT".to_vec()).unwrap();
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name.clone(), SignalParameterValue::U8(1)));
+        System::set_block_number(5);
+        let value = SignalParameterValue::I64(-1_500);
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name.clone(), value));
+
+        assert_eq!(SignalParameterList::<Test>::get(1, &name), Some(SignalParameter { value, changed_at: 5 }));
+        // Changing a parameter does not count it again
+        assert_eq!(SignalParameterCounts::<Test>::get(1), 1);
+        assert_eq!(Signal::signal_parameters_of(&1), vec![(name.into_inner(), SignalParameter { value, changed_at: 5 })]);
+        assert!(Signal::signal_parameters_of(&2).is_empty());
+    });
+}
+
+#[test]
+fn remove_signal_parameter_works_and_emits_event() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let name = BoundedVec::<u8, ConstU32<1024>>::try_from(b"PARAM".to_vec()).unwrap();
+        assert_noop!(
+            Signal::remove_signal_parameter(RuntimeOrigin::signed(1), name.clone()),
+            Error::<Test>::SignalParameterNotSet
+        );
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name.clone(), SignalParameterValue::Bool(true)));
+        // Only the account that set a parameter can remove it
+        assert_noop!(
+            Signal::remove_signal_parameter(RuntimeOrigin::signed(2), name.clone()),
+            Error::<Test>::SignalParameterNotSet
+        );
+
+        assert_ok!(Signal::remove_signal_parameter(RuntimeOrigin::signed(1), name.clone()));
+
+        assert!(!SignalParameterList::<Test>::contains_key(1, &name));
+        assert!(!SignalParameterCounts::<Test>::contains_key(1));
+        System::assert_last_event(Event::SignalParameterRemoved { who: 1, name }.into());
+    });
+}
+
+#[test]
+fn signal_parameters_are_validated_and_limited_per_account() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let name = |name: &[u8]| BoundedVec::<u8, ConstU32<1024>>::try_from(name.to_vec()).unwrap();
+        assert_noop!(
+            Signal::set_signal_parameter(RuntimeOrigin::signed(1), name(b""), SignalParameterValue::U32(1)),
+            Error::<Test>::EmptySignalParameterName
+        );
+        for parameter in [b"A", b"B", b"C"] {
+            assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name(parameter), SignalParameterValue::U32(1)));
+        }
+        assert_noop!(
+            Signal::set_signal_parameter(RuntimeOrigin::signed(1), name(b"D"), SignalParameterValue::U32(1)),
+            Error::<Test>::TooManySignalParameters
+        );
+        // Existing parameters can still be changed, and removing one makes room for another
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name(b"A"), SignalParameterValue::U64(2)));
+        assert_ok!(Signal::remove_signal_parameter(RuntimeOrigin::signed(1), name(b"B")));
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(1), name(b"D"), SignalParameterValue::U32(1)));
+        assert_eq!(SignalParameterCounts::<Test>::get(1), 3);
+        let names: Vec<_> = Signal::signal_parameters_of(&1).into_iter().map(|(name, _)| name).collect();
+        assert_eq!(names, vec![b"A".to_vec(), b"C".to_vec(), b"D".to_vec()]);
+        // The limit applies per account
+        assert_ok!(Signal::set_signal_parameter(RuntimeOrigin::signed(2), name(b"E"), SignalParameterValue::U32(1)));
+    });
+}
+
+#[test]
+fn migration_types_signal_parameters() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(7);
+        StorageVersion::new(1).put::<Signal>();
+        let first = BoundedVec::<u8, ConstU32<1024>>::try_from(b"FIRST".to_vec()).unwrap();
+        let second = BoundedVec::<u8, ConstU32<1024>>::try_from(b"SECOND".to_vec()).unwrap();
+        // Parameters stored as a bare `u8`
+        for (who, name, value) in [(1, &first, 3u8), (1, &second, 200), (2, &first, 9)] {
+            frame_support::storage::unhashed::put(&SignalParameterList::<Test>::hashed_key_for(who, name), &value);
+        }
+
+        migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Signal::on_chain_storage_version(), StorageVersion::new(2));
+        let parameter = |value| Some(SignalParameter { value: SignalParameterValue::U8(value), changed_at: 7 });
+        assert_eq!(SignalParameterList::<Test>::get(1, &first), parameter(3));
+        assert_eq!(SignalParameterList::<Test>::get(1, &second), parameter(200));
+        assert_eq!(SignalParameterList::<Test>::get(2, &first), parameter(9));
+        assert_eq!(SignalParameterCounts::<Test>::get(1), 2);
+        assert_eq!(SignalParameterCounts::<Test>::get(2), 1);
+
+        // Running the migration again changes nothing
+        migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+        assert_eq!(SignalParameterList::<Test>::get(1, &second), parameter(200));
+        assert_eq!(SignalParameterCounts::<Test>::get(1), 2);
+    });
+}
+
+fn held_deposit(who: u64) -> u128 {
+    Balances::balance_on_hold(&HoldReason::RatingDeposit.into(), &who)
+}
+
+fn five_star(rating: u8) -> RatingSignalOf<Test> {
+    RatingSignal { scale: RatingScale::FiveStar, rating, category: None, comment_hash: None }
+}
+
 #[test]
 fn send_rating_signal_works_and_emits_event() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         Balances::make_free_balance_be(&1, 100u32.into());
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), five_star(5)));
         // Storage check
-        assert_eq!(RatingSignalList::<Test>::get(1, &target), 5);
+        assert_eq!(RatingSignalList::<Test>::get(1, &target), Some(five_star(5)));
         // Event check
         System::assert_has_event(Event::SignalLock { account: 1, amount: 10u32.into() }.into());
         System::assert_last_event(Event::RatingSignalSent { who: 1 }.into());
@@ -38,8 +155,8 @@ fn cannot_send_duplicate_rating_signal() {
         System::set_block_number(1);
         Balances::make_free_balance_be(&1, 100u32.into());
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5));
-        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 7), Error::<Test>::RatingSignalAlreadyExists);
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), five_star(5)));
+        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), five_star(4)), Error::<Test>::RatingSignalAlreadyExists);
     });
 }
 
@@ -48,7 +165,7 @@ fn cannot_send_rating_signal_with_insufficient_balance() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target, 5), Error::<Test>::InsufficientBalance);
+        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target, five_star(5)), Error::<Test>::InsufficientBalance);
     });
 }
 
@@ -58,12 +175,11 @@ fn update_rating_signal_works_and_emits_event() {
         System::set_block_number(1);
         Balances::make_free_balance_be(&1, 100u32.into());
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5));
-        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target.clone(), 9));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), five_star(5)));
+        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target.clone(), five_star(3)));
         // Storage check
-        assert_eq!(RatingSignalList::<Test>::get(1, &target), 9);
+        assert_eq!(RatingSignalList::<Test>::get(1, &target), Some(five_star(3)));
         // Event check
-        System::assert_has_event(Event::SignalLockExtended { account: 1, amount: 10u32.into() }.into());
         System::assert_last_event(Event::RatingSignalUpdated { who: 1 }.into());
     });
 }
@@ -74,7 +190,7 @@ fn cannot_update_nonexistent_rating_signal() {
         System::set_block_number(1);
         Balances::make_free_balance_be(&1, 100u32.into());
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_noop!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target, 9), Error::<Test>::RatingSignalDoesNotExist);
+        assert_noop!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target, five_star(3)), Error::<Test>::RatingSignalDoesNotExist);
     });
 }
 
@@ -84,12 +200,12 @@ fn revoke_rating_signal_works_and_emits_event() {
         System::set_block_number(1);
         Balances::make_free_balance_be(&1, 100u32.into());
         let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
-        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), 5));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), five_star(5)));
         assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(1), target.clone()));
         // Storage check
-        assert_eq!(RatingSignalList::<Test>::get(1, &target), 0);
+        assert_eq!(RatingSignalList::<Test>::get(1, &target), None);
         // Event check
-        System::assert_has_event(Event::SignalUnlock { account: 1 }.into());
+        System::assert_has_event(Event::SignalUnlock { account: 1, amount: 10u32.into() }.into());
         System::assert_last_event(Event::RatingSignalRevoked { who: 1 }.into());
     });
 }
@@ -123,3 +239,606 @@ fn send_service_signal_works_and_emits_event() {
         System::assert_last_event(Event::ServiceSignalSent { service_identifier, url, who: 1 }.into());
     });
 }
+
+fn service_deposit(who: u64) -> u128 {
+    Balances::balance_on_hold(&HoldReason::ServiceDeposit.into(), &who)
+}
+
+#[test]
+fn register_service_holds_deposit_and_claims_identifier() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        Balances::make_free_balance_be(&2, 100u32.into());
+        let service_identifier = BoundedVec::<u8, ConstU32<1024>>::try_from(b"SERVICE".to_vec()).unwrap();
+        assert_ok!(Signal::register_service(RuntimeOrigin::signed(1), service_identifier.clone()));
+        System::assert_last_event(Event::ServiceRegistered { service_identifier: service_identifier.clone(), owner: 1 }.into());
+        assert_eq!(service_deposit(1), 25);
+        assert_eq!(Services::<Test>::get(&service_identifier).map(|service| service.owner), Some(1));
+        assert_noop!(
+            Signal::register_service(RuntimeOrigin::signed(2), service_identifier.clone()),
+            Error::<Test>::ServiceAlreadyRegistered
+        );
+        // Without funds for the deposit nothing can be claimed.
+        let other = BoundedVec::<u8, ConstU32<1024>>::try_from(b"OTHER".to_vec()).unwrap();
+        assert_noop!(Signal::register_service(RuntimeOrigin::signed(3), other), Error::<Test>::InsufficientBalance);
+
+        assert_noop!(
+            Signal::unregister_service(RuntimeOrigin::signed(2), service_identifier.clone()),
+            Error::<Test>::NotServiceOwner
+        );
+        assert_ok!(Signal::unregister_service(RuntimeOrigin::signed(1), service_identifier.clone()));
+        System::assert_last_event(Event::ServiceUnregistered { service_identifier: service_identifier.clone(), owner: 1 }.into());
+        assert_eq!(service_deposit(1), 0);
+        assert!(!Services::<Test>::contains_key(&service_identifier));
+        assert_ok!(Signal::register_service(RuntimeOrigin::signed(2), service_identifier));
+    });
+}
+
+#[test]
+fn only_authorized_publishers_send_signals_for_registered_services() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        let service_identifier = BoundedVec::<u8, ConstU32<1024>>::try_from(b"SERVICE".to_vec()).unwrap();
+        let url = BoundedVec::<u8, ConstU32<1024>>::try_from(b"URL".to_vec()).unwrap();
+        assert_ok!(Signal::register_service(RuntimeOrigin::signed(1), service_identifier.clone()));
+
+        assert_ok!(Signal::send_service_signal(RuntimeOrigin::signed(1), service_identifier.clone(), url.clone()));
+        assert_noop!(
+            Signal::send_service_signal(RuntimeOrigin::signed(2), service_identifier.clone(), url.clone()),
+            Error::<Test>::NotServicePublisher
+        );
+
+        assert_noop!(
+            Signal::authorize_service_publisher(RuntimeOrigin::signed(2), service_identifier.clone(), 2),
+            Error::<Test>::NotServiceOwner
+        );
+        assert_ok!(Signal::authorize_service_publisher(RuntimeOrigin::signed(1), service_identifier.clone(), 2));
+        System::assert_last_event(
+            Event::ServicePublisherAuthorized { service_identifier: service_identifier.clone(), publisher: 2 }.into(),
+        );
+        assert_noop!(
+            Signal::authorize_service_publisher(RuntimeOrigin::signed(1), service_identifier.clone(), 2),
+            Error::<Test>::PublisherAlreadyAuthorized
+        );
+        assert_ok!(Signal::send_service_signal(RuntimeOrigin::signed(2), service_identifier.clone(), url.clone()));
+
+        assert_ok!(Signal::revoke_service_publisher(RuntimeOrigin::signed(1), service_identifier.clone(), 2));
+        System::assert_last_event(
+            Event::ServicePublisherRevoked { service_identifier: service_identifier.clone(), publisher: 2 }.into(),
+        );
+        assert_noop!(
+            Signal::revoke_service_publisher(RuntimeOrigin::signed(1), service_identifier.clone(), 2),
+            Error::<Test>::PublisherNotAuthorized
+        );
+        assert_noop!(
+            Signal::send_service_signal(RuntimeOrigin::signed(2), service_identifier, url),
+            Error::<Test>::NotServicePublisher
+        );
+    });
+}
+
+#[test]
+fn service_publishers_are_bounded() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&1, 100u32.into());
+        let service_identifier = BoundedVec::<u8, ConstU32<1024>>::try_from(b"SERVICE".to_vec()).unwrap();
+        assert_noop!(
+            Signal::authorize_service_publisher(RuntimeOrigin::signed(1), service_identifier.clone(), 2),
+            Error::<Test>::ServiceNotRegistered
+        );
+        assert_ok!(Signal::register_service(RuntimeOrigin::signed(1), service_identifier.clone()));
+        for publisher in 2..5 {
+            assert_ok!(Signal::authorize_service_publisher(RuntimeOrigin::signed(1), service_identifier.clone(), publisher));
+        }
+        assert_noop!(
+            Signal::authorize_service_publisher(RuntimeOrigin::signed(1), service_identifier, 5),
+            Error::<Test>::TooManyPublishers
+        );
+    });
+}
+
+#[test]
+fn rating_must_lie_on_its_scale() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
+        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), five_star(0)), Error::<Test>::RatingOutOfScale);
+        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), five_star(6)), Error::<Test>::RatingOutOfScale);
+        let thumbs = |rating| RatingSignal { scale: RatingScale::Thumbs, rating, category: None, comment_hash: None };
+        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), thumbs(2)), Error::<Test>::RatingOutOfScale);
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), thumbs(1)));
+        assert_noop!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target.clone(), five_star(9)), Error::<Test>::RatingOutOfScale);
+    });
+}
+
+#[test]
+fn rating_range_must_not_be_inverted() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
+        let range = |min, max, rating| RatingSignal { scale: RatingScale::Range { min, max }, rating, category: None, comment_hash: None };
+        assert!(!RatingScale::Range { min: 10, max: 1 }.is_valid());
+        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), range(10, 1, 5)), Error::<Test>::InvalidRatingScale);
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), range(3, 3, 3)));
+        assert_noop!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target.clone(), range(4, 2, 3)), Error::<Test>::InvalidRatingScale);
+    });
+}
+
+#[test]
+fn rating_keeps_its_scale_category_and_comment() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
+        let rating = RatingSignal {
+            scale: RatingScale::Percentage,
+            rating: 87,
+            category: Some(BoundedVec::try_from(b"accuracy".to_vec()).unwrap()),
+            comment_hash: Some(BoundedVec::try_from(vec![0x12, 0x20, 0xab]).unwrap()),
+        };
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), rating.clone()));
+        assert_eq!(RatingSignalList::<Test>::get(1, &target), Some(rating));
+        let range = RatingSignal { scale: RatingScale::Range { min: 1, max: 10 }, rating: 10, category: None, comment_hash: None };
+        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), target.clone(), range.clone()));
+        assert_eq!(RatingSignalList::<Test>::get(1, &target), Some(range));
+    });
+}
+
+#[test]
+fn ratings_are_aggregated_per_target_and_scale() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
+        for (who, rating) in [(1, 5), (2, 3), (3, 5)] {
+            Balances::make_free_balance_be(&who, 100u32.into());
+            assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(who), target.clone(), five_star(rating)));
+        }
+        let mut histogram = [0; 10];
+        histogram[4] = 1;
+        histogram[8] = 2;
+        assert_eq!(
+            RatingAggregates::<Test>::get(&target, (1, 5)),
+            Some(RatingAggregate { count: 3, sum: 13, sum_of_squares: 59, histogram })
+        );
+
+        // A custom range with the same bounds shares the aggregate
+        Balances::make_free_balance_be(&4, 100u32.into());
+        let range = RatingSignal { scale: RatingScale::Range { min: 1, max: 5 }, rating: 1, category: None, comment_hash: None };
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(4), target.clone(), range));
+        assert_eq!(RatingAggregates::<Test>::get(&target, (1, 5)).map(|aggregate| aggregate.count), Some(4));
+
+        // Moving a rating to another scale moves it between aggregates
+        let thumbs = RatingSignal { scale: RatingScale::Thumbs, rating: 1, category: None, comment_hash: None };
+        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(2), target.clone(), thumbs));
+        let mut histogram = [0; 10];
+        histogram[0] = 1;
+        histogram[8] = 2;
+        assert_eq!(
+            RatingAggregates::<Test>::get(&target, (1, 5)),
+            Some(RatingAggregate { count: 3, sum: 11, sum_of_squares: 51, histogram })
+        );
+        let mut histogram = [0; 10];
+        histogram[5] = 1;
+        assert_eq!(
+            RatingAggregates::<Test>::get(&target, (0, 1)),
+            Some(RatingAggregate { count: 1, sum: 1, sum_of_squares: 1, histogram })
+        );
+        assert_eq!(Signal::rating_aggregates_of(&target).len(), 2);
+    });
+}
+
+#[test]
+fn revoking_last_rating_removes_aggregate() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        let target = BoundedVec::<u8, ConstU32<1024>>::try_from(b"TARGET".to_vec()).unwrap();
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), target.clone(), five_star(4)));
+        assert!(RatingAggregates::<Test>::contains_key(&target, (1, 5)));
+        assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(1), target.clone()));
+        assert!(!RatingAggregates::<Test>::contains_key(&target, (1, 5)));
+        assert!(Signal::rating_aggregates_of(&target).is_empty());
+    });
+}
+
+#[test]
+fn histogram_buckets_split_the_scale_evenly() {
+    assert_eq!(RatingAggregate::bucket((0, 100), 0), 0);
+    assert_eq!(RatingAggregate::bucket((0, 100), 9), 0);
+    assert_eq!(RatingAggregate::bucket((0, 100), 10), 0);
+    assert_eq!(RatingAggregate::bucket((0, 100), 11), 1);
+    assert_eq!(RatingAggregate::bucket((0, 100), 100), 9);
+    assert_eq!(RatingAggregate::bucket((0, 255), 255), 9);
+    assert_eq!(RatingAggregate::bucket((7, 7), 7), 0);
+}
+
+#[test]
+fn each_rating_holds_its_own_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 100u32.into());
+        let first = BoundedVec::<u8, ConstU32<1024>>::try_from(b"FIRST".to_vec()).unwrap();
+        let second = BoundedVec::<u8, ConstU32<1024>>::try_from(b"SECOND".to_vec()).unwrap();
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), first.clone(), five_star(5)));
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), second.clone(), five_star(2)));
+        assert_eq!(held_deposit(1), 20);
+        assert_eq!(RatingDeposits::<Test>::get(1, &first), Some(10));
+
+        assert_ok!(Signal::update_rating_signal(RuntimeOrigin::signed(1), first.clone(), five_star(4)));
+        assert_eq!(held_deposit(1), 20);
+
+        assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(1), first.clone()));
+        assert_eq!(held_deposit(1), 10);
+        assert_eq!(RatingDeposits::<Test>::get(1, &first), None);
+        assert_eq!(Balances::free_balance(1), 90);
+        assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(1), second));
+        assert_eq!(held_deposit(1), 0);
+        assert_eq!(Balances::free_balance(1), 100);
+    });
+}
+
+#[test]
+fn cannot_send_rating_signal_without_funds_for_another_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 15u32.into());
+        let first = BoundedVec::<u8, ConstU32<1024>>::try_from(b"FIRST".to_vec()).unwrap();
+        let second = BoundedVec::<u8, ConstU32<1024>>::try_from(b"SECOND".to_vec()).unwrap();
+        assert_ok!(Signal::send_rating_signal(RuntimeOrigin::signed(1), first, five_star(5)));
+        assert_noop!(Signal::send_rating_signal(RuntimeOrigin::signed(1), second, five_star(5)), Error::<Test>::InsufficientBalance);
+    });
+}
+
+#[test]
+fn migration_moves_shared_lock_to_holds_per_rating() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        StorageVersion::new(0).put::<Signal>();
+        Balances::make_free_balance_be(&1, 100u32.into());
+        Balances::make_free_balance_be(&2, 15u32.into());
+        let first = BoundedVec::<u8, ConstU32<1024>>::try_from(b"FIRST".to_vec()).unwrap();
+        let second = BoundedVec::<u8, ConstU32<1024>>::try_from(b"SECOND".to_vec()).unwrap();
+        // Ratings stored as a bare `u8` behind one lock per account
+        for (who, target, rating) in [(1, &first, 7u8), (1, &second, 200), (2, &first, 3), (2, &second, 4)] {
+            frame_support::storage::unhashed::put(&RatingSignalList::<Test>::hashed_key_for(who, target), &rating);
+            Balances::set_lock(SignalLockId::get(), &who, 10, WithdrawReasons::all());
+        }
+
+        migrations::v1::MigrateToV1::<Test, Balances, SignalLockId>::on_runtime_upgrade();
+
+        assert_eq!(Signal::on_chain_storage_version(), StorageVersion::new(1));
+        let range = |rating| RatingSignal { scale: RatingScale::Range { min: 0, max: 255 }, rating, category: None, comment_hash: None };
+        assert_eq!(RatingSignalList::<Test>::get(1, &second), Some(range(200)));
+        assert_eq!(RatingSignalList::<Test>::get(2, &first), Some(range(3)));
+        assert!(Balances::locks(&1).is_empty());
+        assert!(Balances::locks(&2).is_empty());
+        assert_eq!(held_deposit(1), 20);
+        assert_eq!(RatingDeposits::<Test>::get(1, &first), Some(10));
+        // Only one of the deposits of account 2 could be held
+        assert_eq!(held_deposit(2), 10);
+        let mut deposits = [RatingDeposits::<Test>::get(2, &first), RatingDeposits::<Test>::get(2, &second)];
+        deposits.sort();
+        assert_eq!(deposits, [Some(0), Some(10)]);
+        assert_eq!(RatingAggregates::<Test>::get(&first, (0, 255)).map(|aggregate| aggregate.sum), Some(10));
+
+        // Revoking releases exactly the rating's share
+        assert_ok!(Signal::revoke_rating_signal(RuntimeOrigin::signed(1), first.clone()));
+        assert_eq!(held_deposit(1), 10);
+
+        // Running the migration again changes nothing
+        migrations::v1::MigrateToV1::<Test, Balances, SignalLockId>::on_runtime_upgrade();
+        assert_eq!(held_deposit(1), 10);
+    });
+}
+
+fn danger_sign() -> whiteflag::Message {
+    whiteflag::Message {
+        header: whiteflag::Header {
+            duress: false,
+            message_code: whiteflag::MessageCode::Danger,
+            reference_indicator: 2,
+            referenced_message: [0x3e; 32],
+        },
+        body: whiteflag::Body::Sign(whiteflag::SignBody {
+            subject_code: 0x80,
+            date_time: whiteflag::DateTime { year: 2026, month: 10, day: 19, hour: 14, minute: 5, second: 59 },
+            duration: whiteflag::Duration { days: 0, hours: 12, minutes: 30 },
+            object_type: 0x22,
+            latitude: 52_09123,
+            longitude: -4_48765,
+            size_dim1: 250,
+            size_dim2: 9999,
+            orientation: 360,
+        }),
+    }
+}
+
+#[test]
+fn whiteflag_messages_round_trip_through_binary_encoding() {
+    let sign = danger_sign();
+    let bytes = sign.to_bytes().unwrap();
+    // A 297 bit header and a 202 bit sign body, padded to whole bytes.
+    assert_eq!(bytes.len(), 63);
+    assert_eq!(&bytes[..3], b"WF1");
+    assert_eq!(whiteflag::Message::from_bytes(&bytes), Ok(sign.clone()));
+
+    let whiteflag::Body::Sign(body) = sign.body.clone() else { unreachable!() };
+    let messages = [
+        whiteflag::Body::Authentication { verification_method: 1, verification_data: b"https://fennellabs.com".to_vec() },
+        whiteflag::Body::Cryptographic { crypto_data_type: 0x11, crypto_data: b"abc".to_vec() },
+        whiteflag::Body::FreeText { text: "Straße closed".as_bytes().to_vec() },
+        whiteflag::Body::Test {
+            pseudo_message_code: whiteflag::MessageCode::Request,
+            body: Box::new(whiteflag::Body::Request {
+                sign: body,
+                objects: vec![whiteflag::ObjectRequest { object_type: 0x10, quantity: 99 }],
+            }),
+        },
+    ];
+    let codes = [
+        whiteflag::MessageCode::Authentication,
+        whiteflag::MessageCode::Cryptographic,
+        whiteflag::MessageCode::FreeText,
+        whiteflag::MessageCode::Test,
+    ];
+    for (body, message_code) in messages.into_iter().zip(codes) {
+        let message = whiteflag::Message { header: whiteflag::Header { message_code, ..sign.header.clone() }, body };
+        assert_eq!(whiteflag::Message::from_bytes(&message.to_bytes().unwrap()), Ok(message));
+    }
+}
+
+#[test]
+fn whiteflag_encoding_rejects_invalid_fields() {
+    let mut message = danger_sign();
+    message.header.message_code = whiteflag::MessageCode::FreeText;
+    assert_eq!(message.to_bytes(), Err(whiteflag::Error::MismatchedBody));
+
+    let mut message = danger_sign();
+    if let whiteflag::Body::Sign(sign) = &mut message.body {
+        sign.latitude = 90_00001;
+    }
+    assert_eq!(message.to_bytes(), Err(whiteflag::Error::InvalidField));
+}
+
+#[test]
+fn whiteflag_decoding_rejects_malformed_messages() {
+    let bytes = danger_sign().to_bytes().unwrap();
+
+    let mut wrong_prefix = bytes.clone();
+    wrong_prefix[0] = b'X';
+    assert_eq!(whiteflag::Message::from_bytes(&wrong_prefix), Err(whiteflag::Error::InvalidPrefix));
+
+    let mut encrypted = bytes.clone();
+    encrypted[3] |= 0x10;
+    assert_eq!(whiteflag::Message::from_bytes(&encrypted), Err(whiteflag::Error::Encrypted));
+
+    let mut unknown_code = bytes.clone();
+    // The message code starts at bit 29, after the duress indicator.
+    unknown_code[3] = (unknown_code[3] & 0xf8) | (b'Z' >> 5);
+    unknown_code[4] = (b'Z' << 3) | (unknown_code[4] & 0x07);
+    assert_eq!(whiteflag::Message::from_bytes(&unknown_code), Err(whiteflag::Error::UnknownMessageCode));
+
+    assert_eq!(whiteflag::Message::from_bytes(&bytes[..62]), Err(whiteflag::Error::UnexpectedEnd));
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(whiteflag::Message::from_bytes(&trailing), Err(whiteflag::Error::TrailingData));
+}
+
+#[test]
+fn send_whiteflag_signal_works_and_emits_event() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let message = BoundedVec::<u8, ConstU32<1024>>::try_from(danger_sign().to_bytes().unwrap()).unwrap();
+        assert_ok!(Signal::send_whiteflag_signal(RuntimeOrigin::signed(1), message.clone()));
+        System::assert_last_event(
+            Event::WhiteflagSignalSent {
+                who: 1,
+                message_code: whiteflag::MessageCode::Danger,
+                duress: false,
+                reference_indicator: 2,
+                referenced_message: [0x3e; 32],
+                message,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn send_whiteflag_signal_rejects_invalid_messages() {
+    new_test_ext().execute_with(|| {
+        let message = BoundedVec::<u8, ConstU32<1024>>::try_from(b"WF1 not a whiteflag message".to_vec()).unwrap();
+        assert_noop!(
+            Signal::send_whiteflag_signal(RuntimeOrigin::signed(1), message),
+            Error::<Test>::InvalidWhiteflagMessage
+        );
+    });
+}
+
+fn payload_deposit(who: u64) -> u128 {
+    Balances::balance_on_hold(&HoldReason::PayloadDeposit.into(), &who)
+}
+
+#[test]
+fn payloads_are_noted_under_their_hash_and_referenced_by_signals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 1_000u32.into());
+        let payload = BoundedVec::<u8, ConstU32<4096>>::try_from(vec![b'w'; 300]).unwrap();
+        let hash = <Test as frame_system::Config>::Hashing::hash(&payload);
+        assert_noop!(Signal::send_payload_signal(RuntimeOrigin::signed(1), hash), Error::<Test>::PayloadNotNoted);
+
+        assert_ok!(Signal::note_payload(RuntimeOrigin::signed(1), payload.clone()));
+        // A base of 5 plus 1 per byte.
+        System::assert_last_event(Event::PayloadNoted { hash, who: 1, deposit: 305 }.into());
+        assert_eq!(payload_deposit(1), 305);
+        assert_eq!(Signal::payload(hash), Some(payload.clone()));
+        assert_eq!(PayloadTickets::<Test>::get(hash).map(|ticket| ticket.len), Some(300));
+        assert_noop!(
+            Signal::note_payload(RuntimeOrigin::signed(2), payload),
+            Error::<Test>::PayloadAlreadyNoted
+        );
+
+        assert_ok!(Signal::send_payload_signal(RuntimeOrigin::signed(2), hash));
+        System::assert_last_event(Event::PayloadSignalSent { hash, who: 2 }.into());
+    });
+}
+
+#[test]
+fn unnoting_a_payload_removes_it_and_releases_its_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        Balances::make_free_balance_be(&1, 1_000u32.into());
+        let payload = BoundedVec::<u8, ConstU32<4096>>::try_from(b"WF1 payload".to_vec()).unwrap();
+        let hash = <Test as frame_system::Config>::Hashing::hash(&payload);
+        assert_ok!(Signal::note_payload(RuntimeOrigin::signed(1), payload));
+
+        assert_noop!(Signal::unnote_payload(RuntimeOrigin::signed(2), hash), Error::<Test>::NotPayloadDepositor);
+        assert_ok!(Signal::unnote_payload(RuntimeOrigin::signed(1), hash));
+        System::assert_last_event(Event::PayloadUnnoted { hash, who: 1 }.into());
+        assert_eq!(payload_deposit(1), 0);
+        assert!(!Payloads::<Test>::contains_key(hash));
+        assert!(!PayloadTickets::<Test>::contains_key(hash));
+        assert_noop!(Signal::unnote_payload(RuntimeOrigin::signed(1), hash), Error::<Test>::PayloadNotNoted);
+        assert_noop!(Signal::send_payload_signal(RuntimeOrigin::signed(1), hash), Error::<Test>::PayloadNotNoted);
+    });
+}
+
+#[test]
+fn cannot_note_payload_without_funds_for_its_deposit() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&1, 100u32.into());
+        let payload = BoundedVec::<u8, ConstU32<4096>>::try_from(vec![0; 200]).unwrap();
+        assert_noop!(Signal::note_payload(RuntimeOrigin::signed(1), payload), Error::<Test>::InsufficientBalance);
+    });
+}
+
+fn signal_call(signal: &[u8]) -> RuntimeCall {
+    RuntimeCall::Signal(crate::Call::send_signal { signal: BoundedVec::try_from(signal.to_vec()).unwrap() })
+}
+
+/// Validates `call` from `who` through `CheckSignalRate` and, if valid, dispatches it.
+fn run_with_rate_limit(who: u64, call: RuntimeCall) -> Result<(), InvalidTransaction> {
+    let info = call.get_dispatch_info();
+    CheckSignalRate::<Test>::new()
+        .validate_only(RuntimeOrigin::signed(who), &call, &info, 0, TransactionSource::External, 0)
+        .map_err(|error| match error {
+            TransactionValidityError::Invalid(invalid) => invalid,
+            _ => panic!("unexpected validity error"),
+        })?;
+    CheckSignalRate::<Test>::new()
+        .test_run(RuntimeOrigin::signed(who), &call, &info, 0, 0, |origin| call.clone().dispatch(origin))
+        .unwrap()
+        .unwrap();
+    Ok(())
+}
+
+#[test]
+fn free_signals_beyond_the_rate_limit_are_rejected_at_validation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(run_with_rate_limit(1, signal_call(b"ONE")), Ok(()));
+        assert_eq!(run_with_rate_limit(1, signal_call(b"TWO")), Ok(()));
+        assert_eq!(run_with_rate_limit(1, signal_call(b"THREE")), Err(InvalidTransaction::Custom(SIGNAL_RATE_LIMITED)));
+        let service_signal = RuntimeCall::Signal(crate::Call::send_service_signal {
+            service_identifier: BoundedVec::try_from(b"SERVICE".to_vec()).unwrap(),
+            url: BoundedVec::try_from(b"URL".to_vec()).unwrap(),
+        });
+        assert_eq!(run_with_rate_limit(1, service_signal), Err(InvalidTransaction::Custom(SIGNAL_RATE_LIMITED)));
+        // Other accounts and calls that are not free signals are unaffected.
+        assert_eq!(run_with_rate_limit(2, signal_call(b"ONE")), Ok(()));
+        let parameter = RuntimeCall::Signal(crate::Call::set_signal_parameter {
+            name: BoundedVec::try_from(b"PARAM".to_vec()).unwrap(),
+            value: SignalParameterValue::U8(1),
+        });
+        assert_eq!(run_with_rate_limit(1, parameter), Ok(()));
+        assert_eq!(SignalRates::<Test>::get(1).map(|rate| rate.count), Some(2));
+    });
+}
+
+#[test]
+fn free_signals_are_prioritised_by_remaining_budget() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let priority = |who: u64, call: RuntimeCall| {
+            let info = call.get_dispatch_info();
+            CheckSignalRate::<Test>::new()
+                .validate_only(RuntimeOrigin::signed(who), &call, &info, 0, TransactionSource::External, 0)
+                .map(|(validity, _, _)| validity.priority)
+        };
+        assert_eq!(priority(1, signal_call(b"ONE")), Ok(2));
+        assert_eq!(run_with_rate_limit(1, signal_call(b"ONE")), Ok(()));
+        assert_eq!(priority(1, signal_call(b"TWO")), Ok(1));
+        assert_eq!(priority(2, signal_call(b"ONE")), Ok(2));
+        // Calls that are not free signals get no priority from the extension.
+        let parameter = RuntimeCall::Signal(crate::Call::set_signal_parameter {
+            name: BoundedVec::try_from(b"PARAM".to_vec()).unwrap(),
+            value: SignalParameterValue::U8(1),
+        });
+        assert_eq!(priority(1, parameter), Ok(0));
+    });
+}
+
+#[test]
+fn rate_limit_resets_after_its_period() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(run_with_rate_limit(1, signal_call(b"ONE")), Ok(()));
+        System::set_block_number(5);
+        assert_eq!(run_with_rate_limit(1, signal_call(b"TWO")), Ok(()));
+        System::set_block_number(10);
+        assert_eq!(run_with_rate_limit(1, signal_call(b"THREE")), Err(InvalidTransaction::Custom(SIGNAL_RATE_LIMITED)));
+        // The period started in block 1 and lasts 10 blocks.
+        System::set_block_number(11);
+        assert_eq!(run_with_rate_limit(1, signal_call(b"THREE")), Ok(()));
+        assert_eq!(Signal::signal_rate(&1, 11), crate::SignalRate { period_start: 11, count: 1 });
+    });
+}
+
+#[test]
+fn ended_rate_periods_are_pruned_on_idle() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(run_with_rate_limit(1, signal_call(b"ONE")), Ok(()));
+        assert_eq!(run_with_rate_limit(3, signal_call(b"ONE")), Ok(()));
+        System::set_block_number(3);
+        assert_eq!(run_with_rate_limit(2, signal_call(b"ONE")), Ok(()));
+        Signal::on_idle(10, Weight::MAX);
+        assert!(SignalRates::<Test>::contains_key(1));
+        // Account 3 starts a new period once its first one has ended
+        System::set_block_number(11);
+        assert_eq!(run_with_rate_limit(3, signal_call(b"TWO")), Ok(()));
+        Signal::on_idle(11, Weight::MAX);
+        assert!(!SignalRates::<Test>::contains_key(1));
+        assert!(!SignalRateExpiry::<Test>::contains_key(11, 1));
+        assert!(SignalRates::<Test>::contains_key(2));
+        assert_eq!(Signal::signal_rates(3), Some(crate::SignalRate { period_start: 11, count: 1 }));
+        Signal::on_idle(13, Weight::MAX);
+        assert!(!SignalRates::<Test>::contains_key(2));
+        Signal::on_idle(21, Weight::MAX);
+        assert_eq!(SignalRates::<Test>::iter().count(), 0);
+        assert_eq!(SignalRateExpiry::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn rate_period_pruning_is_bounded_by_remaining_weight() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(run_with_rate_limit(1, signal_call(b"ONE")), Ok(()));
+        assert_eq!(run_with_rate_limit(2, signal_call(b"ONE")), Ok(()));
+        let db: frame_support::weights::RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+        assert_eq!(Signal::on_idle(11, Weight::zero()), Weight::zero());
+        // Enough weight to reach block 11 and prune one of the two periods ending there
+        let step = db.reads_writes(1, 1) + db.reads(10) + <() as crate::WeightInfo>::prune_signal_rate();
+        assert_eq!(Signal::on_idle(11, step), step);
+        assert_eq!(SignalRates::<Test>::iter().count(), 1);
+        Signal::on_idle(11, Weight::MAX);
+        assert_eq!(SignalRates::<Test>::iter().count(), 0);
+    });
+}
@@ -1,13 +1,13 @@
 
-//! Autogenerated weights for `pallet_signal`
+//! Weights for `pallet_signal`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.0.0
-//! DATE: 2025-05-11, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `DESKTOP-AH3IE72`, CPU: `AMD Ryzen 3 3100 4-Core Processor`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+//! Only `send_signal` was generated by the Substrate benchmark CLI version 47.0.0 (DATE:
+//! 2025-05-11, STEPS: `50`, REPEAT: `20`, CPU: `AMD Ryzen 3 3100 4-Core Processor`,
+//! WASM-EXECUTION: `Compiled`). Every other weight is a hand-written estimate derived from the
+//! storage the call accesses and is marked as such; regenerate this file with the command below
+//! before relying on them.
 
-// Executed Command:
+// Command to regenerate this file:
 // frame-omni-bencher
 // v1
 // benchmark
@@ -39,71 +39,59 @@ pub trait WeightInfo {
 	fn revoke_rating_signal() -> Weight;
 	fn send_signal() -> Weight;
 	fn send_service_signal() -> Weight;
+	fn send_whiteflag_signal() -> Weight;
+	fn register_service() -> Weight;
+	fn unregister_service() -> Weight;
+	fn authorize_service_publisher() -> Weight;
+	fn revoke_service_publisher() -> Weight;
+	fn note_payload(s: u32, ) -> Weight;
+	fn unnote_payload() -> Weight;
+	fn send_payload_signal() -> Weight;
+	fn check_signal_rate() -> Weight;
+	fn remove_signal_parameter() -> Weight;
+	fn prune_signal_rate() -> Weight;
 }
 
 /// Weights for `pallet_signal` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: `Signal::SignalParameterList` (r:0 w:1)
-	/// Proof: `Signal::SignalParameterList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalParameterList` (r:1 w:1)
+	/// Storage: `Signal::SignalParameterCounts` (r:1 w:1)
 	fn set_signal_parameter() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 12_726_000 picoseconds.
-		Weight::from_parts(14_520_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(18_842_000, 3608)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
+	/// Storage: `Signal::RatingDeposits` (r:0 w:1)
 	fn send_rating_signal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `109`
-		//  Estimated: `4764`
-		// Minimum execution time: 55_631_000 picoseconds.
+		// Hand-written estimate, not benchmarked.
 		Weight::from_parts(59_651_000, 4764)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:2 w:2)
 	fn update_rating_signal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `268`
-		//  Estimated: `4764`
-		// Minimum execution time: 51_324_000 picoseconds.
-		Weight::from_parts(54_431_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(24_306_000, 3702)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingDeposits` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
 	fn revoke_rating_signal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `268`
-		//  Estimated: `4764`
-		// Minimum execution time: 51_467_000 picoseconds.
+		// Hand-written estimate, not benchmarked.
 		Weight::from_parts(54_523_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	fn send_signal() -> Weight {
 		// Proof Size summary in bytes:
@@ -112,77 +100,142 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 8_839_000 picoseconds.
 		Weight::from_parts(9_198_000, 0)
 	}
+	/// Storage: `Signal::Services` (r:1 w:0)
 	fn send_service_signal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 9_845_000 picoseconds.
-		Weight::from_parts(16_377_000, 0)
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(16_804_000, 4107)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	fn send_whiteflag_signal() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(11_857_000, 0)
+	}
+	/// Storage: `Signal::Services` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn register_service() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(40_774_000, 4107)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Signal::Services` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn unregister_service() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(41_206_000, 4107)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Signal::Services` (r:1 w:1)
+	fn authorize_service_publisher() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(17_311_000, 4107)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Signal::Services` (r:1 w:1)
+	fn revoke_service_publisher() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(17_902_000, 4107)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Signal::PayloadTickets` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Signal::Payloads` (r:0 w:1)
+	/// The range of component `s` is `[0, 16384]`.
+	fn note_payload(s: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(45_372_406, 3593)
+			.saturating_add(Weight::from_parts(2_617, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Signal::PayloadTickets` (r:1 w:1)
+	/// Storage: `Signal::Payloads` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn unnote_payload() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(44_651_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Signal::PayloadTickets` (r:1 w:0)
+	fn send_payload_signal() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(14_712_000, 3581)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Signal::SignalRates` (r:1 w:1)
+	/// Storage: `Signal::SignalRateCursor` (r:1 w:1)
+	/// Storage: `Signal::SignalRateExpiry` (r:0 w:1)
+	fn check_signal_rate() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(13_240_000, 3521)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Signal::SignalParameterList` (r:1 w:1)
+	/// Storage: `Signal::SignalParameterCounts` (r:1 w:1)
+	fn remove_signal_parameter() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(20_011_000, 3608)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Signal::SignalRateExpiry` (r:0 w:1)
+	/// Storage: `Signal::SignalRates` (r:1 w:1)
+	fn prune_signal_rate() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(9_880_000, 3521)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
-	/// Storage: `Signal::SignalParameterList` (r:0 w:1)
-	/// Proof: `Signal::SignalParameterList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::SignalParameterList` (r:1 w:1)
+	/// Storage: `Signal::SignalParameterCounts` (r:1 w:1)
 	fn set_signal_parameter() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 12_726_000 picoseconds.
-		Weight::from_parts(14_520_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(18_842_000, 3608)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
+	/// Storage: `Signal::RatingDeposits` (r:0 w:1)
 	fn send_rating_signal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `109`
-		//  Estimated: `4764`
-		// Minimum execution time: 55_631_000 picoseconds.
+		// Hand-written estimate, not benchmarked.
 		Weight::from_parts(59_651_000, 4764)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:2 w:2)
 	fn update_rating_signal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `268`
-		//  Estimated: `4764`
-		// Minimum execution time: 51_324_000 picoseconds.
-		Weight::from_parts(54_431_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(24_306_000, 3702)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Signal::RatingSignalList` (r:1 w:1)
-	/// Proof: `Signal::RatingSignalList` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingDeposits` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Signal::RatingAggregates` (r:1 w:1)
 	fn revoke_rating_signal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `268`
-		//  Estimated: `4764`
-		// Minimum execution time: 51_467_000 picoseconds.
+		// Hand-written estimate, not benchmarked.
 		Weight::from_parts(54_523_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	fn send_signal() -> Weight {
 		// Proof Size summary in bytes:
@@ -191,11 +244,99 @@ impl WeightInfo for () {
 		// Minimum execution time: 8_839_000 picoseconds.
 		Weight::from_parts(9_198_000, 0)
 	}
+	/// Storage: `Signal::Services` (r:1 w:0)
 	fn send_service_signal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 9_845_000 picoseconds.
-		Weight::from_parts(16_377_000, 0)
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(16_804_000, 4107)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn send_whiteflag_signal() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(11_857_000, 0)
+	}
+	/// Storage: `Signal::Services` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	fn register_service() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(40_774_000, 4107)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Signal::Services` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn unregister_service() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(41_206_000, 4107)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Signal::Services` (r:1 w:1)
+	fn authorize_service_publisher() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(17_311_000, 4107)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Signal::Services` (r:1 w:1)
+	fn revoke_service_publisher() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(17_902_000, 4107)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Signal::PayloadTickets` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Signal::Payloads` (r:0 w:1)
+	/// The range of component `s` is `[0, 16384]`.
+	fn note_payload(s: u32, ) -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(45_372_406, 3593)
+			.saturating_add(Weight::from_parts(2_617, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Signal::PayloadTickets` (r:1 w:1)
+	/// Storage: `Signal::Payloads` (r:0 w:1)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn unnote_payload() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(44_651_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Signal::PayloadTickets` (r:1 w:0)
+	fn send_payload_signal() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(14_712_000, 3581)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Signal::SignalRates` (r:1 w:1)
+	/// Storage: `Signal::SignalRateCursor` (r:1 w:1)
+	/// Storage: `Signal::SignalRateExpiry` (r:0 w:1)
+	fn check_signal_rate() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(13_240_000, 3521)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Signal::SignalParameterList` (r:1 w:1)
+	/// Storage: `Signal::SignalParameterCounts` (r:1 w:1)
+	fn remove_signal_parameter() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(20_011_000, 3608)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Signal::SignalRateExpiry` (r:0 w:1)
+	/// Storage: `Signal::SignalRates` (r:1 w:1)
+	fn prune_signal_rate() -> Weight {
+		// Hand-written estimate, not benchmarked.
+		Weight::from_parts(9_880_000, 3521)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
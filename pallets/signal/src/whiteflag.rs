@@ -0,0 +1,509 @@
+//! Encoding and decoding of unencrypted Whiteflag protocol messages.
+//!
+//! Whiteflag messages are bit-packed: every field is written most significant bit first and the
+//! message is padded with zero bits to a whole number of bytes. UTF-8 fields take eight bits per
+//! character, hexadecimal fields four bits per digit, decimal fields four bits per digit in binary
+//! coded decimal, and coordinates a sign bit followed by their digits. The codec works without the
+//! standard library so that the pallet can validate messages on-chain.
+
+use alloc::{boxed::Box, vec::Vec};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::RuntimeDebug;
+use scale_info::TypeInfo;
+
+/// The prefix every Whiteflag message starts with.
+pub const PREFIX: [u8; 2] = *b"WF";
+/// The version of the Whiteflag protocol this codec encodes.
+pub const VERSION: u8 = b'1';
+/// The number of bits in the header of a message.
+pub const HEADER_BITS: usize = 297;
+
+/// A reason a message could not be encoded or decoded.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Error {
+    /// The message does not start with `WF`.
+    InvalidPrefix,
+    /// The message is of another protocol version.
+    UnsupportedVersion,
+    /// The message is encrypted, so everything after its encryption indicator is unreadable.
+    Encrypted,
+    /// The message code is not a known message type.
+    UnknownMessageCode,
+    /// A field holds a value outside of its range.
+    InvalidField,
+    /// The body does not belong to the message type of the header.
+    MismatchedBody,
+    /// The message ends before its last field.
+    UnexpectedEnd,
+    /// The message continues after its last field or is padded with set bits.
+    TrailingData,
+}
+
+/// The type of a Whiteflag message.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum MessageCode {
+    /// `A`: authenticates the originator of other messages.
+    Authentication,
+    /// `K`: carries data for cryptographic support, such as key negotiation.
+    Cryptographic,
+    /// `T`: tests the message type given as pseudo message code.
+    Test,
+    /// `R`: points to an external resource.
+    Resource,
+    /// `F`: carries free text.
+    FreeText,
+    /// `P`: marks a protective sign.
+    Protective,
+    /// `E`: signals an emergency.
+    Emergency,
+    /// `D`: marks a danger sign.
+    Danger,
+    /// `S`: signals a status.
+    Status,
+    /// `I`: marks an infrastructure sign.
+    Infrastructure,
+    /// `M`: signals a mission.
+    Mission,
+    /// `Q`: requests objects.
+    Request,
+}
+
+impl MessageCode {
+    /// Returns the character identifying the message type.
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::Authentication => b'A',
+            Self::Cryptographic => b'K',
+            Self::Test => b'T',
+            Self::Resource => b'R',
+            Self::FreeText => b'F',
+            Self::Protective => b'P',
+            Self::Emergency => b'E',
+            Self::Danger => b'D',
+            Self::Status => b'S',
+            Self::Infrastructure => b'I',
+            Self::Mission => b'M',
+            Self::Request => b'Q',
+        }
+    }
+
+    /// Returns the message type identified by `code`, if any.
+    pub fn from_code(code: u8) -> Option<Self> {
+        Some(match code {
+            b'A' => Self::Authentication,
+            b'K' => Self::Cryptographic,
+            b'T' => Self::Test,
+            b'R' => Self::Resource,
+            b'F' => Self::FreeText,
+            b'P' => Self::Protective,
+            b'E' => Self::Emergency,
+            b'D' => Self::Danger,
+            b'S' => Self::Status,
+            b'I' => Self::Infrastructure,
+            b'M' => Self::Mission,
+            b'Q' => Self::Request,
+            _ => return None,
+        })
+    }
+}
+
+/// The header fields of an unencrypted message following its prefix, version and encryption
+/// indicator.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Header {
+    /// Whether the originator sent the message under duress.
+    pub duress: bool,
+    /// The type of the message.
+    pub message_code: MessageCode,
+    /// How the message relates to `referenced_message`, from `0` for an original message to `9`.
+    pub reference_indicator: u8,
+    /// The hash of the message this message refers to, all zeros for an original message.
+    pub referenced_message: [u8; 32],
+}
+
+/// A date and time in UTC.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct DateTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+/// A duration of up to 99 days, 99 hours and 99 minutes.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct Duration {
+    pub days: u8,
+    pub hours: u8,
+    pub minutes: u8,
+}
+
+/// The body shared by signs and signals, describing the object they mark.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct SignBody {
+    /// What the sign or signal is about, depending on the message type.
+    pub subject_code: u8,
+    /// When the sign or signal takes effect.
+    pub date_time: DateTime,
+    /// How long the sign or signal stays in effect.
+    pub duration: Duration,
+    /// The type of the object marked.
+    pub object_type: u8,
+    /// The latitude of the object in hundred-thousandths of a degree.
+    pub latitude: i32,
+    /// The longitude of the object in hundred-thousandths of a degree.
+    pub longitude: i32,
+    /// The first dimension of the object's size in metres, up to 9999.
+    pub size_dim1: u16,
+    /// The second dimension of the object's size in metres, up to 9999.
+    pub size_dim2: u16,
+    /// The orientation of the object in degrees, up to 360.
+    pub orientation: u16,
+}
+
+/// A number of objects of a type asked for by a request.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct ObjectRequest {
+    /// The type of the objects.
+    pub object_type: u8,
+    /// The number of objects, up to 99.
+    pub quantity: u8,
+}
+
+/// The body of a message, whose variant follows from the message code.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum Body {
+    /// The body of an `Authentication` message.
+    Authentication { verification_method: u8, verification_data: Vec<u8> },
+    /// The body of a `Cryptographic` message, whose data is given as hexadecimal characters.
+    Cryptographic { crypto_data_type: u8, crypto_data: Vec<u8> },
+    /// The body of a `Test` message: the body of the message type it tests.
+    Test { pseudo_message_code: MessageCode, body: Box<Body> },
+    /// The body of a `Resource` message.
+    Resource { resource_method: u8, resource_data: Vec<u8> },
+    /// The body of a `FreeText` message.
+    FreeText { text: Vec<u8> },
+    /// The body of a `Protective`, `Emergency`, `Danger`, `Status`, `Infrastructure` or
+    /// `Mission` message.
+    Sign(SignBody),
+    /// The body of a `Request` message.
+    Request { sign: SignBody, objects: Vec<ObjectRequest> },
+}
+
+impl Body {
+    /// Returns whether the body belongs to messages of type `code`.
+    pub fn matches(&self, code: MessageCode) -> bool {
+        use MessageCode::*;
+        match self {
+            Self::Authentication { .. } => code == Authentication,
+            Self::Cryptographic { .. } => code == Cryptographic,
+            Self::Test { pseudo_message_code, body } => {
+                code == Test && *pseudo_message_code != Test && body.matches(*pseudo_message_code)
+            },
+            Self::Resource { .. } => code == Resource,
+            Self::FreeText { .. } => code == FreeText,
+            Self::Sign(_) => matches!(code, Protective | Emergency | Danger | Status | Infrastructure | Mission),
+            Self::Request { .. } => code == Request,
+        }
+    }
+}
+
+/// An unencrypted Whiteflag message.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct Message {
+    pub header: Header,
+    pub body: Body,
+}
+
+impl Message {
+    /// Encodes the message into its binary form.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        ensure(self.body.matches(self.header.message_code), Error::MismatchedBody)?;
+        let mut writer = BitWriter::default();
+        writer.write_bytes(&PREFIX);
+        writer.write(VERSION.into(), 8);
+        writer.write(0, 4);
+        writer.write(self.header.duress.into(), 1);
+        writer.write(self.header.message_code.code().into(), 8);
+        ensure(self.header.reference_indicator <= 9, Error::InvalidField)?;
+        writer.write(self.header.reference_indicator.into(), 4);
+        writer.write_bytes(&self.header.referenced_message);
+        write_body(&mut writer, &self.body)?;
+        Ok(writer.finish())
+    }
+
+    /// Decodes a message from its binary form, validating every field.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = BitReader::new(bytes);
+        ensure(reader.read(16)? == u64::from(u16::from_be_bytes(PREFIX)), Error::InvalidPrefix)?;
+        ensure(reader.read(8)? == u64::from(VERSION), Error::UnsupportedVersion)?;
+        ensure(reader.read(4)? == 0, Error::Encrypted)?;
+        let duress = reader.read(1)? == 1;
+        let message_code = read_message_code(&mut reader)?;
+        let reference_indicator = reader.read(4)? as u8;
+        ensure(reference_indicator <= 9, Error::InvalidField)?;
+        let mut referenced_message = [0u8; 32];
+        for byte in referenced_message.iter_mut() {
+            *byte = reader.read(8)? as u8;
+        }
+        let body = read_body(&mut reader, message_code)?;
+        reader.finish()?;
+        Ok(Self { header: Header { duress, message_code, reference_indicator, referenced_message }, body })
+    }
+}
+
+fn ensure(condition: bool, error: Error) -> Result<(), Error> {
+    if condition {
+        Ok(())
+    } else {
+        Err(error)
+    }
+}
+
+fn write_body(writer: &mut BitWriter, body: &Body) -> Result<(), Error> {
+    match body {
+        Body::Authentication { verification_method, verification_data } => {
+            writer.write_hex(*verification_method, 1)?;
+            writer.write_text(verification_data)?;
+        },
+        Body::Cryptographic { crypto_data_type, crypto_data } => {
+            writer.write(u64::from(*crypto_data_type), 8);
+            for digit in crypto_data {
+                let value = (*digit as char).to_digit(16).ok_or(Error::InvalidField)?;
+                writer.write(value.into(), 4);
+            }
+        },
+        Body::Test { pseudo_message_code, body } => {
+            writer.write(pseudo_message_code.code().into(), 8);
+            write_body(writer, body)?;
+        },
+        Body::Resource { resource_method, resource_data } => {
+            writer.write_hex(*resource_method, 1)?;
+            writer.write_text(resource_data)?;
+        },
+        Body::FreeText { text } => writer.write_text(text)?,
+        Body::Sign(sign) => write_sign(writer, sign)?,
+        Body::Request { sign, objects } => {
+            write_sign(writer, sign)?;
+            for object in objects {
+                writer.write(object.object_type.into(), 8);
+                writer.write_decimal(object.quantity.into(), 2, 99)?;
+            }
+        },
+    }
+    Ok(())
+}
+
+fn write_sign(writer: &mut BitWriter, sign: &SignBody) -> Result<(), Error> {
+    let DateTime { year, month, day, hour, minute, second } = sign.date_time;
+    ensure((1..=12).contains(&month) && (1..=31).contains(&day), Error::InvalidField)?;
+    writer.write(sign.subject_code.into(), 8);
+    writer.write_decimal(year.into(), 4, 9999)?;
+    writer.write_decimal(month.into(), 2, 12)?;
+    writer.write_decimal(day.into(), 2, 31)?;
+    writer.write_decimal(hour.into(), 2, 23)?;
+    writer.write_decimal(minute.into(), 2, 59)?;
+    writer.write_decimal(second.into(), 2, 59)?;
+    writer.write_decimal(sign.duration.days.into(), 2, 99)?;
+    writer.write_decimal(sign.duration.hours.into(), 2, 99)?;
+    writer.write_decimal(sign.duration.minutes.into(), 2, 99)?;
+    writer.write(sign.object_type.into(), 8);
+    writer.write_coordinate(sign.latitude, 7, 90_00000)?;
+    writer.write_coordinate(sign.longitude, 8, 180_00000)?;
+    writer.write_decimal(sign.size_dim1.into(), 4, 9999)?;
+    writer.write_decimal(sign.size_dim2.into(), 4, 9999)?;
+    writer.write_decimal(sign.orientation.into(), 3, 360)?;
+    Ok(())
+}
+
+fn read_message_code(reader: &mut BitReader) -> Result<MessageCode, Error> {
+    MessageCode::from_code(reader.read(8)? as u8).ok_or(Error::UnknownMessageCode)
+}
+
+fn read_body(reader: &mut BitReader, code: MessageCode) -> Result<Body, Error> {
+    Ok(match code {
+        MessageCode::Authentication => Body::Authentication {
+            verification_method: reader.read(4)? as u8,
+            verification_data: reader.read_text()?,
+        },
+        MessageCode::Cryptographic => {
+            let crypto_data_type = reader.read(8)? as u8;
+            let mut crypto_data = Vec::new();
+            while reader.remaining() >= 4 {
+                let digit = char::from_digit(reader.read(4)? as u32, 16).ok_or(Error::InvalidField)?;
+                crypto_data.push(digit as u8);
+            }
+            Body::Cryptographic { crypto_data_type, crypto_data }
+        },
+        MessageCode::Test => {
+            let pseudo_message_code = read_message_code(reader)?;
+            ensure(pseudo_message_code != MessageCode::Test, Error::InvalidField)?;
+            Body::Test { pseudo_message_code, body: Box::new(read_body(reader, pseudo_message_code)?) }
+        },
+        MessageCode::Resource => {
+            Body::Resource { resource_method: reader.read(4)? as u8, resource_data: reader.read_text()? }
+        },
+        MessageCode::FreeText => Body::FreeText { text: reader.read_text()? },
+        MessageCode::Request => {
+            let sign = read_sign(reader)?;
+            let mut objects = Vec::new();
+            while reader.remaining() >= 16 {
+                let object_type = reader.read(8)? as u8;
+                let quantity = reader.read_decimal(2, 99)? as u8;
+                objects.push(ObjectRequest { object_type, quantity });
+            }
+            Body::Request { sign, objects }
+        },
+        _ => Body::Sign(read_sign(reader)?),
+    })
+}
+
+fn read_sign(reader: &mut BitReader) -> Result<SignBody, Error> {
+    let subject_code = reader.read(8)? as u8;
+    let date_time = DateTime {
+        year: reader.read_decimal(4, 9999)? as u16,
+        month: reader.read_decimal(2, 12)? as u8,
+        day: reader.read_decimal(2, 31)? as u8,
+        hour: reader.read_decimal(2, 23)? as u8,
+        minute: reader.read_decimal(2, 59)? as u8,
+        second: reader.read_decimal(2, 59)? as u8,
+    };
+    ensure(date_time.month >= 1 && date_time.day >= 1, Error::InvalidField)?;
+    let duration = Duration {
+        days: reader.read_decimal(2, 99)? as u8,
+        hours: reader.read_decimal(2, 99)? as u8,
+        minutes: reader.read_decimal(2, 99)? as u8,
+    };
+    Ok(SignBody {
+        subject_code,
+        date_time,
+        duration,
+        object_type: reader.read(8)? as u8,
+        latitude: reader.read_coordinate(7, 90_00000)?,
+        longitude: reader.read_coordinate(8, 180_00000)?,
+        size_dim1: reader.read_decimal(4, 9999)? as u16,
+        size_dim2: reader.read_decimal(4, 9999)? as u16,
+        orientation: reader.read_decimal(3, 360)? as u16,
+    })
+}
+
+/// Writes fields bit by bit, most significant bit first.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: usize,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, width: usize) {
+        for shift in (0..width).rev() {
+            if self.bits.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> shift) & 1 == 1 {
+                if let Some(byte) = self.bytes.last_mut() {
+                    *byte |= 0x80 >> (self.bits % 8);
+                }
+            }
+            self.bits += 1;
+        }
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.write((*byte).into(), 8);
+        }
+    }
+
+    fn write_hex(&mut self, value: u8, digits: usize) -> Result<(), Error> {
+        ensure(u64::from(value) < 1 << (4 * digits), Error::InvalidField)?;
+        self.write(value.into(), 4 * digits);
+        Ok(())
+    }
+
+    fn write_text(&mut self, text: &[u8]) -> Result<(), Error> {
+        core::str::from_utf8(text).map_err(|_| Error::InvalidField)?;
+        self.write_bytes(text);
+        Ok(())
+    }
+
+    fn write_decimal(&mut self, value: u32, digits: u32, max: u32) -> Result<(), Error> {
+        ensure(value <= max, Error::InvalidField)?;
+        for position in (0..digits).rev() {
+            self.write(((value / 10u32.pow(position)) % 10).into(), 4);
+        }
+        Ok(())
+    }
+
+    fn write_coordinate(&mut self, value: i32, digits: u32, max: u32) -> Result<(), Error> {
+        self.write((value >= 0).into(), 1);
+        self.write_decimal(value.unsigned_abs(), digits, max)
+    }
+
+    fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Reads fields bit by bit, most significant bit first.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len() * 8 - self.position
+    }
+
+    fn read(&mut self, width: usize) -> Result<u64, Error> {
+        ensure(self.remaining() >= width, Error::UnexpectedEnd)?;
+        let mut value = 0u64;
+        for _ in 0..width {
+            let bit = (self.bytes[self.position / 8] >> (7 - self.position % 8)) & 1;
+            value = (value << 1) | u64::from(bit);
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    fn read_text(&mut self) -> Result<Vec<u8>, Error> {
+        let mut text = Vec::new();
+        while self.remaining() >= 8 {
+            text.push(self.read(8)? as u8);
+        }
+        core::str::from_utf8(&text).map_err(|_| Error::InvalidField)?;
+        Ok(text)
+    }
+
+    fn read_decimal(&mut self, digits: u32, max: u32) -> Result<u32, Error> {
+        let mut value = 0u32;
+        for _ in 0..digits {
+            let digit = self.read(4)? as u32;
+            ensure(digit <= 9, Error::InvalidField)?;
+            value = value * 10 + digit;
+        }
+        ensure(value <= max, Error::InvalidField)?;
+        Ok(value)
+    }
+
+    fn read_coordinate(&mut self, digits: u32, max: u32) -> Result<i32, Error> {
+        let positive = self.read(1)? == 1;
+        let magnitude = self.read_decimal(digits, max)? as i32;
+        Ok(if positive { magnitude } else { -magnitude })
+    }
+
+    /// Checks that only zero padding of less than a byte is left.
+    fn finish(mut self) -> Result<(), Error> {
+        let remaining = self.remaining();
+        ensure(remaining < 8 && self.read(remaining)? == 0, Error::TrailingData)
+    }
+}
@@ -42,8 +42,8 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, Hash, InherentDataExt, Nonce,
+	Runtime, RuntimeCall, RuntimeEvent, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -220,6 +220,188 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_certificate_runtime_api::CertificateApi<Block, AccountId, BlockNumber, Hash> for Runtime {
+		fn verify_certificate(sender: AccountId, recipient: AccountId) -> bool {
+			pallet_certificate::Pallet::<Runtime>::verify_certificate(&sender, &recipient)
+		}
+
+		fn is_certified(sender: AccountId, recipient: AccountId) -> bool {
+			pallet_certificate::Pallet::<Runtime>::is_certified(&sender, &recipient)
+		}
+
+		fn certificates_issued_by(sender: AccountId) -> Vec<AccountId> {
+			pallet_certificate::Pallet::<Runtime>::certificates_issued_by(&sender)
+		}
+
+		fn certificates_held_by(recipient: AccountId) -> Vec<AccountId> {
+			pallet_certificate::Pallet::<Runtime>::certificates_held_by(&recipient)
+		}
+
+		fn certificate(
+			sender: AccountId,
+			recipient: AccountId,
+		) -> Option<pallet_certificate_runtime_api::CertificateDetails<AccountId, BlockNumber, Hash>> {
+			pallet_certificate::Pallet::<Runtime>::active_certificate(&sender, &recipient).map(|certificate| {
+				pallet_certificate_runtime_api::CertificateDetails {
+					claim_type: certificate.claim_type.into_inner(),
+					content_hash: certificate.content_hash,
+					issued_at: certificate.issued_at,
+					extrinsic_index: certificate.extrinsic_index,
+					expires_at: certificate.expires_at,
+					authority_chain: certificate.authority_chain.map(|chain| chain.into_inner()),
+				}
+			})
+		}
+	}
+
+	impl pallet_infostratus_runtime_api::InfostratusApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn open_submissions(
+			start_after: Option<(AccountId, Vec<u8>)>,
+			limit: u32,
+		) -> Vec<pallet_infostratus_runtime_api::OpenSubmission<AccountId, Balance, BlockNumber>> {
+			let start_after = match start_after {
+				Some((poster, resource_location)) => match resource_location.try_into() {
+					Ok(resource_location) => Some((poster, resource_location)),
+					Err(_) => return Vec::new(),
+				},
+				None => None,
+			};
+			pallet_infostratus::Pallet::<Runtime>::open_submissions(start_after, limit)
+				.into_iter()
+				.map(|(poster, resource_location, submission)| {
+					let metadata =
+						pallet_infostratus::Pallet::<Runtime>::submission_metadata(&poster, &resource_location);
+					let (title, tags) = metadata
+						.map(|metadata| {
+							(
+								metadata.title.into_inner(),
+								metadata.tags.into_iter().map(|tag| tag.into_inner()).collect(),
+							)
+						})
+						.unwrap_or_default();
+					pallet_infostratus_runtime_api::OpenSubmission {
+						poster,
+						resource_location: resource_location.into_inner(),
+						title,
+						tags,
+						bounty: submission.bounty,
+						created_at: submission.created_at,
+					}
+				})
+				.collect()
+		}
+
+		fn assignments_of(
+			assignee: AccountId,
+			start_after: Option<Vec<u8>>,
+			limit: u32,
+		) -> Vec<pallet_infostratus_runtime_api::Assignment<AccountId>> {
+			let start_after = match start_after.map(TryInto::try_into).transpose() {
+				Ok(start_after) => start_after,
+				Err(_) => return Vec::new(),
+			};
+			pallet_infostratus::Pallet::<Runtime>::assignments_of(&assignee, start_after, limit)
+				.into_iter()
+				.map(|(resource_location, poster)| pallet_infostratus_runtime_api::Assignment {
+					poster,
+					resource_location: resource_location.into_inner(),
+				})
+				.collect()
+		}
+	}
+
+	impl pallet_signal_runtime_api::SignalApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn rating_aggregates(target: Vec<u8>) -> Vec<pallet_signal_runtime_api::RatingAggregate> {
+			let Ok(target) = target.try_into() else {
+				return Vec::new();
+			};
+			pallet_signal::Pallet::<Runtime>::rating_aggregates_of(&target)
+				.into_iter()
+				.map(|((min, max), aggregate)| pallet_signal_runtime_api::RatingAggregate {
+					min,
+					max,
+					count: aggregate.count,
+					sum: aggregate.sum,
+					sum_of_squares: aggregate.sum_of_squares,
+					histogram: aggregate.histogram.to_vec(),
+				})
+				.collect()
+		}
+
+		fn rating_aggregate(target: Vec<u8>, min: u8, max: u8) -> Option<pallet_signal_runtime_api::RatingAggregate> {
+			let target: frame_support::BoundedVec<u8, <Runtime as pallet_signal::Config>::MaxSize> =
+				target.try_into().ok()?;
+			pallet_signal::Pallet::<Runtime>::rating_aggregates(target, (min, max)).map(|aggregate| {
+				pallet_signal_runtime_api::RatingAggregate {
+					min,
+					max,
+					count: aggregate.count,
+					sum: aggregate.sum,
+					sum_of_squares: aggregate.sum_of_squares,
+					histogram: aggregate.histogram.to_vec(),
+				}
+			})
+		}
+
+		fn payload(hash: Hash) -> Option<Vec<u8>> {
+			pallet_signal::Pallet::<Runtime>::payload(hash).map(|payload| payload.into_inner())
+		}
+
+		fn block_signals() -> Vec<pallet_signal_runtime_api::SignalRecord<AccountId, Hash>> {
+			use pallet_signal::Event as SignalEvent;
+			use pallet_signal_runtime_api::{SignalKind, SignalRecord};
+
+			System::read_events_no_consensus()
+				.filter_map(|record| {
+					let extrinsic_index = match record.phase {
+						frame_system::Phase::ApplyExtrinsic(index) => Some(index),
+						_ => None,
+					};
+					let RuntimeEvent::Signal(event) = record.event else {
+						return None;
+					};
+					let (sender, kind, payload) = match event {
+						SignalEvent::SignalSent { signal, who } => (who, SignalKind::Signal, signal.into_inner()),
+						SignalEvent::ServiceSignalSent { service_identifier, url, who } => (
+							who,
+							SignalKind::Service { service_identifier: service_identifier.into_inner() },
+							url.into_inner(),
+						),
+						SignalEvent::WhiteflagSignalSent { who, message, .. } => {
+							(who, SignalKind::Whiteflag, message.into_inner())
+						},
+						SignalEvent::PayloadSignalSent { hash, who } => {
+							let payload = pallet_signal::Pallet::<Runtime>::payload(hash).unwrap_or_default();
+							(who, SignalKind::Payload { hash }, payload.into_inner())
+						},
+						_ => return None,
+					};
+					Some(SignalRecord { extrinsic_index, sender, kind, payload })
+				})
+				.collect()
+		}
+
+		fn signal_parameters(who: AccountId) -> Vec<pallet_signal_runtime_api::SignalParameter<BlockNumber>> {
+			use pallet_signal::SignalParameterValue as Value;
+			use pallet_signal_runtime_api::SignalParameterValue;
+
+			pallet_signal::Pallet::<Runtime>::signal_parameters_of(&who)
+				.into_iter()
+				.map(|(name, parameter)| pallet_signal_runtime_api::SignalParameter {
+					name,
+					value: match parameter.value {
+						Value::Bool(value) => SignalParameterValue::Bool(value),
+						Value::U8(value) => SignalParameterValue::U8(value),
+						Value::U32(value) => SignalParameterValue::U32(value),
+						Value::U64(value) => SignalParameterValue::U64(value),
+						Value::I64(value) => SignalParameterValue::I64(value),
+					},
+					changed_at: parameter.changed_at,
+				})
+				.collect()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -7,6 +7,8 @@ pub mod apis;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
 pub mod configs;
+#[cfg(test)]
+mod tests;
 
 extern crate alloc;
 use alloc::vec::Vec;
@@ -74,10 +76,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	system_version: 1,
 };
 
@@ -160,6 +162,7 @@ pub type TxExtension = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	pallet_signal::CheckSignalRate<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	frame_system::WeightReclaim<Runtime>,
@@ -176,7 +179,12 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_certificate::migrations::v1::MigrateToV1<Runtime, Balances, CertificateLockId>,
+	pallet_infostratus::migrations::v1::MigrateToV1<Runtime, Balances, InfostratusLockId>,
+	pallet_signal::migrations::v1::MigrateToV1<Runtime, Balances, SignalLockId>,
+	pallet_signal::migrations::v2::MigrateToV2<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -229,16 +237,25 @@ impl pallet_validator_manager::Config for Runtime {
 }
 
 parameter_types! {
+    /// The lock certificate deposits were stored under before
+    /// `pallet_certificate::migrations::v1`.
     pub const CertificateLockId: [u8; 8] = *b"certlock";
     pub const CertificateLockPrice: u32 = 4_294_967_295; // max u32 value
+    pub const CertificatePendingPeriod: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_certificate::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_certificate::weights::SubstrateWeight<Runtime>;
     type Currency = pallet_balances::Pallet<Runtime>;
-    type LockId = CertificateLockId;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type LockPrice = CertificateLockPrice;
+    type MaxClaimTypeLength = ConstU32<64>;
+    type MaxRevocationHistory = ConstU32<8>;
+    type PendingPeriod = CertificatePendingPeriod;
+    type AuthorityOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxDelegationDepth = ConstU32<4>;
+    type MaxBulkCertificates = ConstU32<256>;
 }
 
 // Implement the Config trait for the identity pallet in the runtime
@@ -256,33 +273,94 @@ impl pallet_keystore::Config for Runtime {
 }
 
 parameter_types! {
+    /// The lock submission and assignment deposits were stored under before
+    /// `pallet_infostratus::migrations::v1`.
     pub const InfostratusLockId: [u8; 8] = *b"infosloc";
     pub const InfostratusLockPrice: u32 = 1_000_000_000; // set as needed
     pub const InfostratusMaxSize: u32 = 64; // Adjust as needed
+    pub const InfostratusDisputePeriod: BlockNumber = 3 * DAYS;
+    pub const InfostratusSubmissionTtl: BlockNumber = 30 * DAYS;
+    pub const InfostratusAssignmentPeriod: BlockNumber = 7 * DAYS;
+    pub const InfostratusMaxTitleLength: u32 = 256;
+    pub const InfostratusMaxContentHashLength: u32 = 68; // a 64-byte digest plus its code and length
+    pub const InfostratusMaxMimeTypeLength: u32 = 128;
+    pub const InfostratusMaxTagLength: u32 = 32;
+    pub const InfostratusMaxTags: u32 = 16;
+    pub const InfostratusMaxReviewers: u32 = 16;
+}
+
+/// Lets an account take on or review a submission if it holds a certificate from the issuer the
+/// poster chose that still verifies, including its authority chain, or if the poster trusts it
+/// and has not revoked that trust.
+pub struct CertifiedOrTrustedReviewer;
+
+impl pallet_infostratus::ReviewerEligibility<AccountId> for CertifiedOrTrustedReviewer {
+    fn is_eligible(poster: &AccountId, reviewer: &AccountId, issuer: Option<&AccountId>) -> bool {
+        issuer.is_some_and(|issuer| pallet_certificate::Pallet::<Runtime>::verify_certificate(issuer, reviewer)) ||
+            (pallet_trust::TrustIssuance::<Runtime>::contains_key(poster, reviewer) &&
+                !pallet_trust::TrustRevocation::<Runtime>::contains_key(poster, reviewer))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn make_eligible(poster: &AccountId, reviewer: &AccountId) {
+        pallet_trust::TrustIssuance::<Runtime>::insert(poster, reviewer, 0);
+    }
 }
 
 impl pallet_infostratus::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_infostratus::weights::SubstrateWeight<Runtime>;
     type Currency = pallet_balances::Pallet<Runtime>;
-    type LockId = InfostratusLockId;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type LockPrice = InfostratusLockPrice;
     type MaxSize = InfostratusMaxSize;
+    type DisputePeriod = InfostratusDisputePeriod;
+    type DisputeOrigin = frame_system::EnsureRoot<AccountId>;
+    type SubmissionTtl = InfostratusSubmissionTtl;
+    type AssignmentPeriod = InfostratusAssignmentPeriod;
+    type MaxTitleLength = InfostratusMaxTitleLength;
+    type MaxContentHashLength = InfostratusMaxContentHashLength;
+    type MaxMimeTypeLength = InfostratusMaxMimeTypeLength;
+    type MaxTagLength = InfostratusMaxTagLength;
+    type MaxTags = InfostratusMaxTags;
+    type MaxReviewers = InfostratusMaxReviewers;
+    type ReviewerEligibility = CertifiedOrTrustedReviewer;
 }
 
 parameter_types! {
+    /// The lock rating deposits were stored under before `pallet_signal::migrations::v1`.
     pub const SignalLockId: [u8; 8] = *b"signallk";
     pub const SignalLockPrice: u32 = 1_000_000_000; // set as needed
     pub const SignalMaxSize: u32 = 64; // adjust as needed
+    pub const SignalMaxCategoryLength: u32 = 32;
+    pub const SignalMaxCommentHashLength: u32 = 68; // a 64-byte digest plus its code and length
+    pub const SignalServiceDeposit: u32 = 4_000_000_000;
+    pub const SignalMaxServicePublishers: u32 = 16;
+    pub const SignalMaxPayloadSize: u32 = 16 * 1024;
+    pub const SignalPayloadDepositBase: u32 = 1_000_000_000;
+    pub const SignalPayloadDepositPerByte: u32 = 1_000_000;
+    pub const SignalMaxSignalsPerPeriod: u32 = 10;
+    pub const SignalRatePeriod: BlockNumber = 10 * MINUTES;
+    pub const SignalMaxSignalParameters: u32 = 64;
 }
 
 impl pallet_signal::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_signal::weights::SubstrateWeight<Runtime>;
     type Currency = pallet_balances::Pallet<Runtime>;
-    type LockId = SignalLockId;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type LockPrice = SignalLockPrice;
     type MaxSize = SignalMaxSize;
+    type MaxCategoryLength = SignalMaxCategoryLength;
+    type MaxCommentHashLength = SignalMaxCommentHashLength;
+    type ServiceDeposit = SignalServiceDeposit;
+    type MaxServicePublishers = SignalMaxServicePublishers;
+    type MaxPayloadSize = SignalMaxPayloadSize;
+    type PayloadDepositBase = SignalPayloadDepositBase;
+    type PayloadDepositPerByte = SignalPayloadDepositPerByte;
+    type MaxSignalsPerPeriod = SignalMaxSignalsPerPeriod;
+    type SignalRatePeriod = SignalRatePeriod;
+    type MaxSignalParameters = SignalMaxSignalParameters;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
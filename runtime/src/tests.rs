@@ -0,0 +1,32 @@
+use frame_support::assert_ok;
+use pallet_infostratus::ReviewerEligibility;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::BuildStorage;
+
+use crate::{AccountId, CertifiedOrTrustedReviewer, Runtime, RuntimeOrigin, System, Trust};
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+#[test]
+fn revoked_trust_does_not_make_a_reviewer_eligible() {
+	new_test_ext().execute_with(|| {
+		let poster: AccountId = Sr25519Keyring::Alice.to_account_id();
+		let reviewer: AccountId = Sr25519Keyring::Bob.to_account_id();
+		assert!(!CertifiedOrTrustedReviewer::is_eligible(&poster, &reviewer, None));
+
+		assert_ok!(Trust::issue_trust(RuntimeOrigin::signed(poster.clone()), reviewer.clone()));
+		assert!(CertifiedOrTrustedReviewer::is_eligible(&poster, &reviewer, None));
+
+		// Revoking trust does not remove the earlier issuance, but it must still win
+		assert_ok!(Trust::revoke_trust(RuntimeOrigin::signed(poster.clone()), reviewer.clone()));
+		assert!(!CertifiedOrTrustedReviewer::is_eligible(&poster, &reviewer, None));
+
+		assert_ok!(Trust::remove_revoked_trust(RuntimeOrigin::signed(poster.clone()), reviewer.clone()));
+		assert!(CertifiedOrTrustedReviewer::is_eligible(&poster, &reviewer, None));
+	});
+}